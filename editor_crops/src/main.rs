@@ -3,7 +3,7 @@ use std::{
     path::PathBuf,
 };
 
-use game_core::crops::data::CropDefinition;
+use game_core::crops::data::{CropDefinition, CropStage};
 use iced::{
     widget::{button, column, container, keyed_column, row, scrollable, text},
     Element, Theme,
@@ -147,9 +147,32 @@ impl Screen {
                     .spacing(10),
                 )
             }
-            Screen::Edit { file: _, data } => {
-                container(text(format!("Under construction : {:#?}", data))).into()
-            }
+            Screen::Edit { file: _, data } => container(scrollable(
+                column![
+                    text(format!("Crop: {}", data.id)),
+                    text(if data.seasons.is_empty() {
+                        "Seasons: all year".into()
+                    } else {
+                        format!("Seasons: {:?}", data.seasons)
+                    }),
+                    text(match data.max_harvests {
+                        Some(max) => format!("Max harvests: {}", max),
+                        None => "Max harvests: unlimited".into(),
+                    }),
+                    keyed_column(
+                        data.stages
+                            .iter()
+                            .enumerate()
+                            .map(|(index, stage)| {
+                                text(stage_summary(index, stage, data.stages.len())).into()
+                            })
+                            .enumerate(),
+                    )
+                    .spacing(5)
+                ]
+                .spacing(10),
+            ))
+            .into(),
         }
         .padding(20)
         .center(300)
@@ -157,6 +180,25 @@ impl Screen {
     }
 }
 
+/// Single line description of a stage, flagging regrow targets that point past the last stage
+fn stage_summary(index: usize, stage: &CropStage, stage_count: usize) -> String {
+    let regrow = match stage.regrow_to {
+        Some(target) if target >= stage_count => {
+            format!(" -> regrows to missing stage {}!", target)
+        }
+        Some(target) => format!(" -> regrows to stage {}", target),
+        None => "".into(),
+    };
+    let drought = match stage.drought_tolerance {
+        Some(days) => format!(", dies after {} dry days", days),
+        None => "".into(),
+    };
+    format!(
+        "Stage {}: {} ({}-{} days{}){}",
+        index, stage.model, stage.duration.min, stage.duration.max, drought, regrow
+    )
+}
+
 impl App {
     fn update(&mut self, message: Messages) {
         if let Messages::ChangeScreen(n_screen) = message {
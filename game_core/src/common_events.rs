@@ -4,6 +4,11 @@
 
 use bevy::prelude::*;
 
+use crate::{
+    days::{Date, Season},
+    items::{drops::ItemDrop, ItemId, ItemQuality, ToolKind},
+};
+
 #[derive(Event, Debug)]
 pub struct PlayerInteractionChanged(pub Option<Entity>);
 
@@ -14,20 +19,93 @@ pub struct CropStageChange {
     pub name: Option<Name>,
 }
 
+/// Fired whenever a crop gives up its fruit, whether it was picked or its stage ran out on its own
+#[derive(Event, Debug, Clone)]
+pub struct CropHarvested {
+    pub entity: Entity,
+    pub crop: String,
+    pub position: Vec3,
+    pub drops: Vec<ItemDrop>,
+}
+
 #[derive(Event, Debug, Clone)]
 pub struct PlayerInteract;
 
+/// Fired when an item from the world makes it into the player's inventory
+#[derive(Event, Debug, Clone)]
+pub struct ItemPickedUp {
+    pub item: ItemId,
+    pub amount: u32,
+    pub quality: ItemQuality,
+}
+
+/// Fired when the player swings the tool in their hand. Targets the entity they're looking at, if
+/// there is one
+#[derive(Event, Debug, Clone)]
+pub struct ToolUsed {
+    pub tool: ToolKind,
+    /// Whether this was the secondary action rather than the primary one
+    pub secondary: bool,
+    /// Where the player was aiming in the world
+    pub point: Option<Vec3>,
+}
+
 pub struct CommonEventsPlugin;
 
+/// Fired when the clock hits the end of the day while the player is still awake. Anything that
+/// should penalise staying up too late can hook in here
+#[derive(Event, Debug, Clone)]
+pub struct PlayerPassedOut;
+
+/// Fired once the screen has faded to black and the player is asleep, right before [NewDay]
+#[derive(Event, Debug, Clone)]
+pub struct PlayerSlept {
+    pub passed_out: bool,
+}
+
+/// Fired when the day rolls over, carrying the new date
 #[derive(Event, Debug, Clone)]
-pub struct NewDay;
+pub struct NewDay(pub Date);
+
+/// Fired after [NewDay] when the day rolls over into a new season
+#[derive(Event, Debug, Clone)]
+pub struct NewSeason(pub Season);
+
+/// Fired after [NewSeason] when the seasons wrap around into a new year
+#[derive(Event, Debug, Clone)]
+pub struct NewYear(pub u32);
+
+/// Fired after [NewDay] once the shipping bin has been paid out, if anything was in it
+#[derive(Event, Debug, Clone)]
+pub struct DayEarnings {
+    pub sold: Vec<SoldItem>,
+    /// Money added to the wallet
+    pub total: u32,
+}
+
+/// A single stack sold from the shipping bin
+#[derive(Debug, Clone)]
+pub struct SoldItem {
+    pub item: ItemId,
+    pub quality: ItemQuality,
+    pub amount: u32,
+    pub earned: u32,
+}
 
 impl Plugin for CommonEventsPlugin {
     fn build(&self, app: &mut App) {
         app.observe(easy_event_print::<PlayerInteractionChanged>);
         app.observe(easy_event_print::<CropStageChange>);
+        app.observe(easy_event_print::<CropHarvested>);
         app.observe(easy_event_print::<PlayerInteract>);
+        app.observe(easy_event_print::<ItemPickedUp>);
+        app.observe(easy_event_print::<ToolUsed>);
+        app.observe(easy_event_print::<PlayerPassedOut>);
+        app.observe(easy_event_print::<PlayerSlept>);
         app.observe(easy_event_print::<NewDay>);
+        app.observe(easy_event_print::<NewSeason>);
+        app.observe(easy_event_print::<NewYear>);
+        app.observe(easy_event_print::<DayEarnings>);
     }
 }
 
@@ -1,8 +1,8 @@
 use bevy::prelude::*;
 
-use crate::items::drops::ItemDrop;
+use crate::{days::Season, items::drops::ItemDrop};
 
-use super::data::CropStage;
+use super::data::{CropDefinition, CropStage};
 
 #[derive(Debug, Bundle, Default)]
 pub struct CropBundle {
@@ -19,13 +19,33 @@ impl CropBundle {
 
         Ok(Self { data })
     }
+
+    /// Creates a new crop bundle from a loaded definition, carrying over the crop id. Returns
+    /// `None` if the definition has no stages
+    pub fn from_definition(def: &CropDefinition) -> Option<Self> {
+        let mut bundle = Self::new(def.stages.clone()).ok()?;
+        bundle.data.id = def.id.clone();
+        bundle.data.max_harvests = def.max_harvests;
+        bundle.data.seasons = def.seasons.clone();
+        Some(bundle)
+    }
 }
 
+/// The definition a crop was spawned from. Crops are not constructed until this finishes loading
+#[derive(Component, Debug, Clone)]
+pub struct CropHandle(pub Handle<CropDefinition>);
+
 #[derive(Component, Debug, Default, Clone)]
 pub struct CropData {
     pub id: String,
     pub stages: Vec<CropStage>,
     pub index: usize,
+    /// Times the crop has finished a regrowing stage
+    pub harvests: u32,
+    pub max_harvests: Option<u32>,
+    /// Consecutive days the crop has gone without water
+    pub dry_days: u32,
+    pub seasons: Vec<Season>,
 }
 
 impl CropData {
@@ -34,7 +54,43 @@ impl CropData {
             id: "".into(),
             stages: stages.into_iter().collect::<Vec<_>>(),
             index: 0,
+            harvests: 0,
+            max_harvests: None,
+            dry_days: 0,
+            seasons: vec![],
+        }
+    }
+
+    pub fn grows_in(&self, season: Season) -> bool {
+        self.seasons.is_empty() || self.seasons.contains(&season)
+    }
+
+    /// Whether the crop is still allowed to loop back to an earlier stage
+    pub fn can_regrow(&self) -> bool {
+        self.max_harvests.is_none_or(|max| self.harvests < max)
+    }
+
+    /// Whether the crop has gone without water for longer than its current stage can handle
+    pub fn is_parched(&self) -> bool {
+        self.stages
+            .get(self.index)
+            .and_then(|stage| stage.drought_tolerance)
+            .is_some_and(|tolerance| self.dry_days > tolerance)
+    }
+
+    /// Moves onto the stage after the current one, looping back when the current stage regrows.
+    /// Returns `None` once the crop has reached the end of its cycle
+    pub fn advance(&mut self) -> Option<&CropStage> {
+        let regrow_to = self.stages.get(self.index).and_then(|s| s.regrow_to);
+        if regrow_to.is_some() {
+            // counted here instead of on picking, so regrow stages without fruit still run out
+            self.harvests += 1;
         }
+        self.index = match regrow_to {
+            Some(target) if self.can_regrow() => target,
+            _ => self.index + 1,
+        };
+        self.stages.get(self.index)
     }
 }
 
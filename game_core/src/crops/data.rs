@@ -1,6 +1,6 @@
 use bevy::{asset::Asset, reflect::TypePath};
 
-use crate::data::range::Range;
+use crate::{data::range::Range, days::Season, items::drops::ItemDrop};
 
 use super::components::CropStatus;
 
@@ -8,6 +8,42 @@ use super::components::CropStatus;
 pub struct CropDefinition {
     pub id: String,
     pub stages: Vec<CropStage>,
+    /// How many times a regrowing crop can be harvested before it carries on past its regrow
+    /// stages. Unlimited when unset
+    #[serde(default)]
+    pub max_harvests: Option<u32>,
+    /// Seasons the crop can be planted and grown in. Crops with no seasons listed grow all year
+    #[serde(default)]
+    pub seasons: Vec<Season>,
+}
+
+impl CropDefinition {
+    pub fn grows_in(&self, season: Season) -> bool {
+        self.seasons.is_empty() || self.seasons.contains(&season)
+    }
+
+    /// Every item drop across all stages
+    pub fn drops(&self) -> impl Iterator<Item = &ItemDrop> {
+        self.stages
+            .iter()
+            .filter_map(|stage| match &stage.begin_status {
+                Some(CropStatus::Fruiting { drops, .. } | CropStatus::Seeding { drops, .. }) => {
+                    Some(drops)
+                }
+                _ => None,
+            })
+            .flatten()
+    }
+
+    /// Stages whose `regrow_to` points past the last stage. These end the crop's cycle instead of
+    /// looping back
+    pub fn invalid_regrows(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.stages
+            .iter()
+            .enumerate()
+            .filter_map(|(index, stage)| Some((index, stage.regrow_to?)))
+            .filter(|(_, target)| *target >= self.stages.len())
+    }
 }
 
 #[derive(Debug, Default, Clone, serde::Deserialize, serde::Serialize, TypePath)]
@@ -15,4 +51,12 @@ pub struct CropStage {
     pub model: String,
     pub duration: Range,
     pub begin_status: Option<CropStatus>,
+    /// The stage index to loop back to once this stage finishes, letting crops like tomatoes
+    /// produce more than once
+    #[serde(default)]
+    pub regrow_to: Option<usize>,
+    /// How many days in a row this stage can go without water before the crop dies. Crops in a
+    /// stage without a tolerance stall but never die of drought
+    #[serde(default)]
+    pub drought_tolerance: Option<u32>,
 }
@@ -1,24 +1,31 @@
 use bevy::prelude::*;
-use bevy_common_assets::json::JsonAssetPlugin;
+use bevy_common_assets::ron::RonAssetPlugin;
 use data::CropDefinition;
-use systems::add_test_crop;
+use registry::CropRegistry;
 use systems::initialize_crops;
+use systems::load_crops;
+use systems::reload_crops;
 use systems::update_crops;
+use systems::wither_out_of_season;
 
 pub mod components;
 pub mod data;
+pub mod registry;
 pub mod systems;
 pub struct CropsPlugin;
 
 impl Plugin for CropsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(JsonAssetPlugin::<CropDefinition>::new(&[".json"]));
-        app.add_systems(Startup, add_test_crop);
+        app.add_plugins(RonAssetPlugin::<CropDefinition>::new(&["crop.ron"]));
+        app.init_resource::<CropRegistry>();
+        app.add_systems(Startup, registry::scan_crops);
         if false {
             // dumb little toggle for me
             app.add_systems(Startup, systems::emit_data_file);
         }
-        app.add_systems(Update, initialize_crops);
+        app.add_systems(Update, (load_crops, initialize_crops).chain());
+        app.add_systems(Update, (reload_crops, registry::index_crops));
         app.observe(update_crops);
+        app.observe(wither_out_of_season);
     }
 }
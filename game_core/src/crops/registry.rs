@@ -0,0 +1,69 @@
+use bevy::{asset::LoadedFolder, ecs::system::SystemParam, prelude::*};
+
+use crate::data::{game_asset_path::GameAssetPath, named_asset_id::FolderRegistry};
+
+use super::data::CropDefinition;
+
+/// Every crop definition found in `::crops`, indexed by [CropDefinition::id]
+#[derive(Resource, Default)]
+pub struct CropRegistry {
+    crops: FolderRegistry<CropDefinition>,
+}
+
+impl CropRegistry {
+    pub fn get(&self, id: impl Into<String>) -> Option<Handle<CropDefinition>> {
+        self.crops.get(id)
+    }
+
+    pub fn contains(&self, id: impl Into<String>) -> bool {
+        self.crops.contains(id)
+    }
+
+    /// All known crop ids, in no particular order
+    pub fn ids(&self) -> impl Iterator<Item = &String> {
+        self.crops.names()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Handle<CropDefinition>)> {
+        self.crops.iter()
+    }
+
+    /// Whether the crops folder has finished loading and been indexed
+    pub fn is_loaded(&self) -> bool {
+        self.crops.is_loaded()
+    }
+}
+
+/// Looks up loaded [CropDefinition]s by id
+#[derive(SystemParam)]
+pub struct CropDefinitions<'w> {
+    registry: Res<'w, CropRegistry>,
+    definitions: Res<'w, Assets<CropDefinition>>,
+}
+
+impl CropDefinitions<'_> {
+    /// The handle for a crop id along with its definition
+    pub fn get(&self, id: impl Into<String>) -> Option<(Handle<CropDefinition>, &CropDefinition)> {
+        let handle = self.registry.get(id)?;
+        let def = self.definitions.get(handle.id())?;
+        Some((handle, def))
+    }
+}
+
+pub(super) fn scan_crops(mut registry: ResMut<CropRegistry>, assets: Res<AssetServer>) {
+    registry
+        .crops
+        .scan(&assets, GameAssetPath::new_data("::crops"));
+}
+
+pub(super) fn index_crops(
+    mut events: EventReader<AssetEvent<LoadedFolder>>, mut registry: ResMut<CropRegistry>,
+    folders: Res<Assets<LoadedFolder>>, definitions: Res<Assets<CropDefinition>>,
+) {
+    let Some(folder) = registry.crops.loaded_folder(&mut events, &folders) else {
+        return;
+    };
+    registry
+        .crops
+        .index(folder, &definitions, "crop", |def| def.id.clone());
+}
@@ -3,15 +3,20 @@ use std::fs::File;
 use avian3d::prelude::{
     Collider, ColliderConstructor, ColliderConstructorHierarchy, ColliderDensity, RigidBody,
 };
-use bevy::{ecs::system::EntityCommands, prelude::*};
+use bevy::{asset::LoadState, ecs::system::EntityCommands, prelude::*};
 use bevy_rand::prelude::{GlobalEntropy, WyRand};
 use ron::{extensions::Extensions, ser::PrettyConfig};
 
 use crate::{
-    common_events::{CropStageChange, NewDay},
+    common_events::{CropHarvested, CropStageChange, NewDay, NewSeason, PlayerInteract, ToolUsed},
     data::{game_asset_path::GameAssetPath, range::Range},
+    days::{Calendar, Season},
+    farm::{FarmGrid, PlantedIn, SoilState, SoilTile},
     interaction::Interactable,
-    items::{drops::ItemDrop, ItemId},
+    items::{
+        drops::{DropSpawner, ItemDrop},
+        ItemId, ToolKind,
+    },
 };
 
 use super::{
@@ -32,11 +37,15 @@ pub(super) fn emit_data_file() {
                 model: "::crate-color.glb".into(),
                 duration: Range { min: 1, max: 2 },
                 begin_status: Some(CropStatus::Growing),
+                regrow_to: None,
+                drought_tolerance: Some(2),
             },
             CropStage {
                 model: "::crate-color.glb".into(),
                 duration: Range { min: 1, max: 2 },
                 begin_status: None,
+                regrow_to: None,
+                drought_tolerance: Some(2),
             },
             CropStage {
                 model: "::crate-color.glb".into(),
@@ -44,12 +53,16 @@ pub(super) fn emit_data_file() {
                 begin_status: Some(CropStatus::Fruiting {
                     model: "::crate-color.glb".into(),
                     drops: vec![ItemDrop {
-                        item: ItemId("test".into()),
+                        item: ItemId("test_item".into()),
                         amount: Range { min: 1, max: 3 },
                     }],
                 }),
+                regrow_to: Some(1),
+                drought_tolerance: None,
             },
         ],
+        max_harvests: Some(3),
+        seasons: vec![Season::Spring, Season::Summer],
     };
     match ron::ser::to_writer_pretty(
         file_ron,
@@ -64,40 +77,79 @@ pub(super) fn emit_data_file() {
     }
 }
 
-pub fn add_test_crop(mut cmd: Commands) {
-    crop_from_asset("corn".into(), &mut cmd, Vec3::new(5., 0., 5.));
-    crop_from_asset("beets".into(), &mut cmd, Vec3::new(-5., 0., 5.));
+/// Spawns a crop into a soil tile, returning `None` if the tile can't be planted in. The crop is
+/// only fully constructed once the definition finishes loading, see [load_crops]
+pub fn plant_crop(
+    cmd: &mut Commands, crop: Handle<CropDefinition>, tile_entity: Entity, tile: &mut SoilTile,
+) -> Option<Entity> {
+    if !tile.can_plant() {
+        return None;
+    }
+    let position = FarmGrid::tile_center(tile.coord);
+    let entity = cmd
+        .spawn((
+            Name::new(format!("Crop - {},{}", tile.coord.x, tile.coord.y)),
+            // Todo component `cleanup::{??}`
+            SpatialBundle {
+                transform: Transform::from_translation(position).with_scale(Vec3::ONE * 5.),
+                ..default()
+            },
+            CropHandle(crop),
+            PlantedIn(tile_entity),
+        ))
+        .id();
+    tile.crop = Some(entity);
+    Some(entity)
 }
 
-fn crop_from_asset(file: &str, cmd: &mut Commands, position: Vec3) {
-    let gap = GameAssetPath::new_data(format!("::crops/{}.crop.ron", file));
-    let Ok(reader) = File::open(gap.path_relative()) else {
-        error!("File not found: {}", gap);
-        return;
-    };
-    let def = match ron::de::from_reader::<_, CropDefinition>(reader) {
-        // TODO: this should definitely be ported over to a more Asset based approach
-        Ok(d) => d,
-        Err(e) => {
-            error!("Failed to deserialize data from {}, {}", file, e);
-            return;
+/// Waits on the load state of each pending crop definition and inserts the [CropBundle] once it is
+/// available. Crops whose definition failed to load are despawned
+pub fn load_crops(
+    query: Query<(&CropHandle, Entity), Without<CropData>>, mut cmd: Commands,
+    assets: Res<AssetServer>, definitions: Res<Assets<CropDefinition>>,
+    calendar: Option<Res<Calendar>>,
+) {
+    for (handle, entity) in query.iter() {
+        match assets.load_state(handle.0.id()) {
+            LoadState::Loaded => (),
+            LoadState::Failed(err) => {
+                error!("Failed to load crop definition for {}: {}", entity, err);
+                cmd.entity(entity).despawn_recursive();
+                continue;
+            }
+            // still waiting on the asset server
+            _ => continue,
         }
-    };
+        let Some(def) = definitions.get(handle.0.id()) else {
+            continue;
+        };
+        if let Some(calendar) = &calendar {
+            if !def.grows_in(calendar.season) {
+                warn!("{} can't be planted in {:?}", def.id, calendar.season);
+                cmd.entity(entity).despawn_recursive();
+                continue;
+            }
+        }
+        warn_invalid_regrows(def);
+        let Some(bundle) = CropBundle::from_definition(def) else {
+            error!(
+                "Failed to construct a crop bundle from definition: {}",
+                def.id
+            );
+            cmd.entity(entity).despawn_recursive();
+            continue;
+        };
+        cmd.entity(entity).insert(bundle);
+    }
+}
 
-    let Ok(bundle) = CropBundle::new(def.stages) else {
-        error!("Failed to construct a crop bundle from file");
-        return;
-    };
-    cmd.spawn((
-        Name::new(format!("{} - {:.1},{:.1}", def.id, position.x, position.z)),
-        // Todo component `cleanup::{??}`
-        SpatialBundle {
-            transform: Transform::from_xyz(position.x, position.y, position.z)
-                .with_scale(Vec3::ONE * 5.),
-            ..default()
-        },
-        bundle,
-    ));
+fn warn_invalid_regrows(def: &CropDefinition) {
+    for (index, target) in def.invalid_regrows() {
+        warn!(
+            "Stage {} of {} regrows to missing stage {}, its cycle will end there instead",
+            index, def.id, target
+        );
+    }
 }
 
 pub fn initialize_crops(
@@ -111,18 +163,23 @@ pub fn initialize_crops(
         };
         let scene = assets.load::<Scene>(GameAssetPath::new_model(start.model).gltf_scene());
 
-        cmd.entity(entity).insert((
-            Name::new(format!("Crop {}", data.id)),
-            start.begin_status.unwrap_or_default(),
-            CropTimer(start.duration.get(&mut rng)),
-            scene,
-            RigidBody::Static,
-            Interactable,
-            ColliderConstructorHierarchy::new(ColliderConstructor::ConvexHullFromMesh),
-        ));
+        cmd.entity(entity)
+            .insert((
+                Name::new(format!("Crop {}", data.id)),
+                start.begin_status.unwrap_or_default(),
+                CropTimer(start.duration.get(&mut rng)),
+                scene,
+                RigidBody::Static,
+                Interactable,
+                ColliderConstructorHierarchy::new(ColliderConstructor::ConvexHullFromMesh),
+            ))
+            .observe(harvest_crop)
+            .observe(scythe_crop);
     }
 }
 
+/// Advances every planted crop by a day. Each crop is processed independently, moving onto its
+/// next stage once its [CropTimer] runs out
 pub fn update_crops(
     _: Trigger<NewDay>,
     mut query: Query<(
@@ -130,71 +187,290 @@ pub fn update_crops(
         &mut CropTimer,
         &GlobalTransform,
         &CropStatus,
+        Option<&CropFruit>,
+        Option<&PlantedIn>,
         Option<&Name>,
         Entity,
     )>,
-    mut cmd: Commands, mut rng: ResMut<GlobalEntropy<WyRand>>, assets: Res<AssetServer>,
-    children_query: Query<&Children>,
+    mut cmd: Commands, mut spawner: DropSpawner, assets: Res<AssetServer>,
+    children_query: Query<&Children>, tiles: Query<&SoilTile>,
 ) {
-    for (mut data, mut timer, trans, last_status, name, entity) in query.iter_mut() {
-        if let Some(safe_num) = timer.0.checked_sub(1) {
-            // we did not try to subtract 1 from 0 (disallowed on u32)
-            timer.0 = safe_num;
-            return;
+    for (mut data, mut timer, trans, last_status, fruit, planted, name, entity) in query.iter_mut()
+    {
+        if *last_status == CropStatus::Dead {
+            cmd.entity(entity).despawn_recursive();
+            continue;
         }
-        data.index += 1;
-        let Some(stage) = data.stages.get(data.index) else {
-            warn!("Crop reached end of cycle: {:} ({:?})", entity, name);
-            cmd.entity(entity).despawn();
-            return;
-        };
-
-        cmd.trigger(CropStageChange {
+        // crops outside of soil (mostly in testing) have nothing to dry out
+        let watered = planted.is_none_or(|planted| {
+            tiles
+                .get(planted.0)
+                .is_ok_and(|tile| tile.state == SoilState::Watered)
+        });
+        if !watered {
+            data.dry_days += 1;
+            if data.is_parched() {
+                info!("Crop died of drought: {:} ({:?})", entity, name);
+                cmd.entity(entity)
+                    .insert(CropStatus::Dead)
+                    .remove::<CropFruit>();
+            }
+            continue;
+        }
+        data.dry_days = 0;
+        timer.0 = timer.0.saturating_sub(1);
+        if timer.0 > 0 {
+            continue;
+        }
+        let change = CropStageChange {
             entity,
             name: name.cloned(),
             position: trans.translation(),
-        });
+        };
+        // fruiting and seeding stages drop their rewards when they finish
+        let drops = fruit.map(|fruit| fruit.0.clone());
+        finish_stage(
+            &mut cmd,
+            change,
+            &mut data,
+            drops,
+            &mut spawner,
+            &assets,
+            &children_query,
+        );
+    }
+}
 
-        for child in children_query.iter_descendants(entity) {
-            // removing colliders allows for regeneration based on the changing scene
-            cmd.entity(child).remove::<Collider>();
+/// Crops that can't grow in the new season wither and are cleared away the next day
+pub fn wither_out_of_season(
+    trigger: Trigger<NewSeason>, query: Query<(&CropData, &CropStatus, Option<&Name>, Entity)>,
+    mut cmd: Commands,
+) {
+    let NewSeason(season) = trigger.event();
+    for (data, status, name, entity) in query.iter() {
+        if data.grows_in(*season) || *status == CropStatus::Dead {
+            continue;
         }
-        set_crop_model(&mut cmd.entity(entity), &stage.model, &assets);
+        info!("Crop withered out of season: {:} ({:?})", entity, name);
+        cmd.entity(entity)
+            .insert(CropStatus::Dead)
+            .remove::<CropFruit>();
+    }
+}
 
-        match last_status {
-            CropStatus::Growing => (),
-            CropStatus::Dead => cmd.entity(entity).despawn(),
-            CropStatus::Seeding { model: _, drops } => {
-                info!("Dropping items: {:#?}", drops);
-            }
-            CropStatus::Fruiting { model: _, drops } => {
-                info!("Dropping items: {:#?}", drops);
-            }
-        }
+/// Lets the player harvest a fruiting crop early, yielding its fruit and moving it onto the next
+/// stage
+pub fn harvest_crop(
+    trigger: Trigger<PlayerInteract>,
+    mut query: Query<(
+        &mut CropData,
+        &CropStatus,
+        Option<&CropFruit>,
+        &GlobalTransform,
+        Option<&Name>,
+    )>,
+    mut cmd: Commands, mut spawner: DropSpawner, assets: Res<AssetServer>,
+    children_query: Query<&Children>,
+) {
+    let entity = trigger.entity();
+    let Ok((mut data, status, fruit, trans, name)) = query.get_mut(entity) else {
+        return;
+    };
+    if !matches!(status, CropStatus::Fruiting { .. }) {
+        return;
+    }
+    let drops = fruit.map(|f| f.0.clone()).unwrap_or_default();
+    let change = CropStageChange {
+        entity,
+        name: name.cloned(),
+        position: trans.translation(),
+    };
+    finish_stage(
+        &mut cmd,
+        change,
+        &mut data,
+        Some(drops),
+        &mut spawner,
+        &assets,
+        &children_query,
+    );
+}
 
-        // Crop Status Stuff
+/// Scything a fruiting crop harvests it the same as picking it by hand, and clears away dead crops
+pub fn scythe_crop(trigger: Trigger<ToolUsed>, query: Query<&CropStatus>, mut cmd: Commands) {
+    let entity = trigger.entity();
+    let ToolUsed {
+        tool: ToolKind::Scythe,
+        secondary: false,
+        ..
+    } = trigger.event()
+    else {
+        return;
+    };
+    match query.get(entity) {
+        Ok(CropStatus::Fruiting { .. }) => cmd.trigger_targets(PlayerInteract, entity),
+        Ok(CropStatus::Dead) => cmd.entity(entity).despawn_recursive(),
+        _ => (),
+    }
+}
 
-        let Some(new_status) = &stage.begin_status else {
-            return;
-        };
-        cmd.entity(entity).insert((new_status.clone(),));
+/// Drops the crop's fruit (if it has any) and moves it onto its next stage. Crops at the end of
+/// their cycle are despawned
+fn finish_stage(
+    cmd: &mut Commands, change: CropStageChange, data: &mut CropData, drops: Option<Vec<ItemDrop>>,
+    spawner: &mut DropSpawner, assets: &Res<AssetServer>, children_query: &Query<&Children>,
+) {
+    let entity = change.entity;
+    if let Some(drops) = drops {
+        spawner.spawn(cmd, &drops, change.position);
+        cmd.entity(entity).remove::<CropFruit>();
+        cmd.trigger(CropHarvested {
+            entity,
+            crop: data.id.clone(),
+            position: change.position,
+            drops,
+        });
+    }
+    let Some(stage) = data.advance().cloned() else {
+        info!("Crop reached end of cycle: {:} ({:?})", entity, change.name);
+        cmd.entity(entity).despawn_recursive();
+        return;
+    };
+    enter_stage(
+        cmd,
+        change,
+        &stage,
+        assets,
+        &mut spawner.rng,
+        children_query,
+    );
+}
 
-        match new_status {
-            CropStatus::Fruiting { model, drops } => {
-                cmd.entity(entity).insert((CropFruit(drops.clone()),));
-                set_crop_model(&mut cmd.entity(entity), model, &assets);
-            }
-            CropStatus::Seeding { model, drops } => {
-                cmd.entity(entity).insert((CropFruit(drops.clone()),));
-                set_crop_model(&mut cmd.entity(entity), model, &assets);
+/// Moves a crop onto `stage`, swapping its model and applying the stage's status (if it has one)
+fn enter_stage(
+    cmd: &mut Commands, change: CropStageChange, stage: &CropStage, assets: &Res<AssetServer>,
+    rng: &mut ResMut<GlobalEntropy<WyRand>>, children_query: &Query<&Children>,
+) {
+    let entity = change.entity;
+    cmd.trigger(change);
+
+    for child in children_query.iter_descendants(entity) {
+        // removing colliders allows for regeneration based on the changing scene
+        cmd.entity(child).remove::<Collider>();
+    }
+    set_crop_model(&mut cmd.entity(entity), &stage.model, assets);
+    cmd.entity(entity)
+        .insert((CropTimer(stage.duration.get(rng)),));
+
+    // Crop Status Stuff
+
+    let Some(new_status) = &stage.begin_status else {
+        return;
+    };
+    cmd.entity(entity).insert((new_status.clone(),));
+
+    match new_status {
+        CropStatus::Fruiting { model, drops } => {
+            cmd.entity(entity).insert((CropFruit(drops.clone()),));
+            set_crop_model(&mut cmd.entity(entity), model, assets);
+        }
+        CropStatus::Seeding { model, drops } => {
+            cmd.entity(entity).insert((CropFruit(drops.clone()),));
+            set_crop_model(&mut cmd.entity(entity), model, assets);
+        }
+        CropStatus::Dead => {
+            cmd.entity(entity).despawn_recursive();
+        }
+        CropStatus::Growing => (),
+    }
+}
+
+/// Re-syncs planted crops whenever their definition is modified on disk, so balancing can be done
+/// without restarting the game. Files are only watched when built with the `dev` feature
+/// (`cargo run --features dev`), otherwise this never fires
+pub fn reload_crops(
+    mut events: EventReader<AssetEvent<CropDefinition>>,
+    mut query: Query<(
+        &CropHandle,
+        &mut CropData,
+        &CropStatus,
+        &GlobalTransform,
+        Option<&Name>,
+        Entity,
+    )>,
+    mut cmd: Commands, definitions: Res<Assets<CropDefinition>>, assets: Res<AssetServer>,
+    mut rng: ResMut<GlobalEntropy<WyRand>>, children_query: Query<&Children>,
+) {
+    for event in events.read() {
+        let AssetEvent::Modified { id } = event else {
+            continue;
+        };
+        let Some(def) = definitions.get(*id) else {
+            continue;
+        };
+        for (handle, mut data, status, trans, name, entity) in query.iter_mut() {
+            if handle.0.id() != *id {
+                continue;
             }
-            CropStatus::Dead => {
-                cmd.entity(entity).despawn();
+            if def.stages.is_empty() {
+                warn!(
+                    "Crop definition {} no longer has any stages, removing {}",
+                    def.id, entity
+                );
+                cmd.entity(entity).despawn_recursive();
+                continue;
             }
-            CropStatus::Growing => {
+            warn_invalid_regrows(def);
+            data.id = def.id.clone();
+            data.stages = def.stages.clone();
+            data.max_harvests = def.max_harvests;
+            data.seasons = def.seasons.clone();
+            // stages may have been removed out from under us
+            let index = data.index.min(data.stages.len() - 1);
+            let moved = index != data.index;
+            data.index = index;
+            let stage = data.stages[index].clone();
+
+            let fruiting = matches!(
+                status,
+                CropStatus::Fruiting { .. } | CropStatus::Seeding { .. }
+            );
+            let stage_fruits = matches!(
+                stage.begin_status,
+                Some(CropStatus::Fruiting { .. } | CropStatus::Seeding { .. })
+            );
+            if moved || (fruiting && !stage_fruits) {
+                // the crop's old stage is gone, so start over on the one it landed on
                 cmd.entity(entity)
-                    .insert((CropTimer(stage.duration.get(&mut rng)),));
+                    .remove::<CropFruit>()
+                    .insert(CropStatus::default());
+                let change = CropStageChange {
+                    entity,
+                    name: name.cloned(),
+                    position: trans.translation(),
+                };
+                enter_stage(&mut cmd, change, &stage, &assets, &mut rng, &children_query);
+            } else {
+                for child in children_query.iter_descendants(entity) {
+                    cmd.entity(child).remove::<Collider>();
+                }
+                match &stage.begin_status {
+                    Some(
+                        new_status @ (CropStatus::Fruiting { model, drops }
+                        | CropStatus::Seeding { model, drops }),
+                    ) if fruiting => {
+                        // keep the fruit in sync with any changed drops
+                        cmd.entity(entity)
+                            .insert((new_status.clone(), CropFruit(drops.clone())));
+                        set_crop_model(&mut cmd.entity(entity), model, &assets);
+                    }
+                    _ => set_crop_model(&mut cmd.entity(entity), &stage.model, &assets),
+                }
             }
+            info!(
+                "Reloaded crop {} ({}) at stage {}",
+                def.id, entity, data.index
+            );
         }
     }
 }
@@ -208,3 +484,275 @@ fn set_crop_model<S: Into<String> + Clone>(
             .with_default_density(ColliderDensity(1.0)),
     ));
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use bevy_rand::prelude::{EntropyPlugin, WyRand};
+
+    use super::*;
+    use crate::{days::Date, items::registry::ItemRegistry};
+
+    fn stage(days: u32) -> CropStage {
+        CropStage {
+            model: "::crate-color.glb".into(),
+            duration: Range {
+                min: days,
+                max: days,
+            },
+            begin_status: Some(CropStatus::Growing),
+            regrow_to: None,
+            drought_tolerance: None,
+        }
+    }
+
+    fn crop_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin {
+                // there's no assets folder to watch from here
+                watch_for_changes_override: Some(false),
+                ..default()
+            },
+            EntropyPlugin::<WyRand>::default(),
+        ));
+        app.init_asset::<Scene>();
+        app.init_asset::<CropDefinition>();
+        app.init_resource::<ItemRegistry>();
+        app.add_systems(Update, (initialize_crops, reload_crops));
+        app.observe(update_crops);
+        app.observe(wither_out_of_season);
+        app.observe(crate::farm::dry_soil);
+        app
+    }
+
+    fn plant(app: &mut App, durations: &[u32]) -> Entity {
+        let bundle = CropBundle::new(durations.iter().map(|d| stage(*d))).unwrap();
+        let entity = app
+            .world_mut()
+            .spawn((SpatialBundle::default(), bundle))
+            .id();
+        // lets `initialize_crops` set up the timer and status
+        app.update();
+        entity
+    }
+
+    fn soil(app: &mut App, state: SoilState) -> Entity {
+        app.world_mut()
+            .spawn(SoilTile {
+                coord: IVec2::ZERO,
+                state,
+                crop: None,
+            })
+            .id()
+    }
+
+    fn pass_days(app: &mut App, days: u32) {
+        for _ in 0..days {
+            app.world_mut().trigger(NewDay(Date::default()));
+            app.update();
+        }
+    }
+
+    fn index_of(app: &App, entity: Entity) -> Option<usize> {
+        app.world().get::<CropData>(entity).map(|data| data.index)
+    }
+
+    #[test]
+    fn every_crop_advances_on_new_day() {
+        let mut app = crop_app();
+        let fast = plant(&mut app, &[1, 1, 1, 1, 1]);
+        let slow = plant(&mut app, &[2, 2, 2, 2, 2]);
+        let late = plant(&mut app, &[3, 1, 1, 1, 1]);
+
+        pass_days(&mut app, 3);
+
+        assert_eq!(index_of(&app, fast), Some(3));
+        assert_eq!(index_of(&app, slow), Some(1));
+        assert_eq!(index_of(&app, late), Some(1));
+
+        pass_days(&mut app, 1);
+
+        assert_eq!(index_of(&app, fast), Some(4));
+        assert_eq!(index_of(&app, slow), Some(2));
+        assert_eq!(index_of(&app, late), Some(2));
+    }
+
+    #[test]
+    fn crop_despawns_at_end_of_cycle() {
+        let mut app = crop_app();
+        let short = plant(&mut app, &[1, 1]);
+        let long = plant(&mut app, &[1, 1, 1, 1]);
+
+        pass_days(&mut app, 2);
+
+        assert!(app.world().get_entity(short).is_none());
+        assert_eq!(index_of(&app, long), Some(2));
+    }
+
+    #[test]
+    fn regrowing_crop_loops_until_max_harvests() {
+        let mut app = crop_app();
+        let mut stages = [stage(1), stage(1), stage(1)];
+        stages[2].begin_status = Some(CropStatus::Fruiting {
+            model: "::crate-color.glb".into(),
+            drops: vec![],
+        });
+        stages[2].regrow_to = Some(1);
+        let mut bundle = CropBundle::new(stages).unwrap();
+        bundle.data.max_harvests = Some(2);
+        let crop = app
+            .world_mut()
+            .spawn((SpatialBundle::default(), bundle))
+            .id();
+        app.update();
+
+        pass_days(&mut app, 2);
+        assert_eq!(index_of(&app, crop), Some(2));
+        // first harvest loops back
+        pass_days(&mut app, 1);
+        assert_eq!(index_of(&app, crop), Some(1));
+        pass_days(&mut app, 1);
+        assert_eq!(index_of(&app, crop), Some(2));
+        // second harvest hits the limit and finishes the cycle
+        pass_days(&mut app, 1);
+        assert!(app.world().get_entity(crop).is_none());
+    }
+
+    #[test]
+    fn regrowing_without_fruit_still_runs_out() {
+        let mut app = crop_app();
+        let mut stages = [stage(1), stage(1)];
+        stages[1].regrow_to = Some(0);
+        let mut bundle = CropBundle::new(stages).unwrap();
+        bundle.data.max_harvests = Some(2);
+        let crop = app
+            .world_mut()
+            .spawn((SpatialBundle::default(), bundle))
+            .id();
+        app.update();
+
+        pass_days(&mut app, 2);
+        assert_eq!(index_of(&app, crop), Some(0));
+        pass_days(&mut app, 2);
+        assert!(app.world().get_entity(crop).is_none());
+    }
+
+    #[test]
+    fn crops_only_grow_in_watered_soil() {
+        let mut app = crop_app();
+        let wet = soil(&mut app, SoilState::Watered);
+        let dry = soil(&mut app, SoilState::Tilled);
+        let watered = plant(&mut app, &[1, 1, 1]);
+        let parched = plant(&mut app, &[1, 1, 1]);
+        app.world_mut().entity_mut(watered).insert(PlantedIn(wet));
+        app.world_mut().entity_mut(parched).insert(PlantedIn(dry));
+
+        pass_days(&mut app, 1);
+
+        assert_eq!(index_of(&app, watered), Some(1));
+        assert_eq!(index_of(&app, parched), Some(0));
+        // the soil dries out overnight, but only after the crop has had its drink
+        assert_eq!(
+            app.world().get::<SoilTile>(wet).map(|tile| tile.state),
+            Some(SoilState::Tilled)
+        );
+
+        pass_days(&mut app, 1);
+
+        assert_eq!(index_of(&app, watered), Some(1));
+    }
+
+    #[test]
+    fn crop_dies_after_drought_tolerance() {
+        let mut app = crop_app();
+        let dry = soil(&mut app, SoilState::Tilled);
+        let mut thirsty = stage(1);
+        thirsty.drought_tolerance = Some(2);
+        let bundle = CropBundle::new([thirsty, stage(1)]).unwrap();
+        let crop = app
+            .world_mut()
+            .spawn((SpatialBundle::default(), bundle, PlantedIn(dry)))
+            .id();
+        app.update();
+
+        pass_days(&mut app, 2);
+        assert_eq!(
+            app.world().get::<CropStatus>(crop),
+            Some(&CropStatus::Growing)
+        );
+        pass_days(&mut app, 1);
+        assert_eq!(app.world().get::<CropStatus>(crop), Some(&CropStatus::Dead));
+        // dead crops are cleared away the following day
+        pass_days(&mut app, 1);
+        assert!(app.world().get_entity(crop).is_none());
+    }
+
+    #[test]
+    fn out_of_season_crops_wither() {
+        let mut app = crop_app();
+        let summer = plant(&mut app, &[1, 1, 1]);
+        let all_year = plant(&mut app, &[1, 1, 1]);
+        app.world_mut().get_mut::<CropData>(summer).unwrap().seasons = vec![Season::Summer];
+
+        app.world_mut().trigger(NewSeason(Season::Autumn));
+        app.update();
+
+        assert_eq!(
+            app.world().get::<CropStatus>(summer),
+            Some(&CropStatus::Dead)
+        );
+        assert_eq!(
+            app.world().get::<CropStatus>(all_year),
+            Some(&CropStatus::Growing)
+        );
+    }
+
+    #[test]
+    fn reloading_onto_a_plain_stage_clears_the_fruit() {
+        let mut app = crop_app();
+        let mut fruiting = stage(5);
+        fruiting.begin_status = Some(CropStatus::Fruiting {
+            model: "::crate-color.glb".into(),
+            drops: vec![],
+        });
+        let def = CropDefinition {
+            id: "Reloaded".into(),
+            stages: vec![stage(1), fruiting],
+            max_harvests: None,
+            seasons: vec![],
+        };
+        let bundle = CropBundle::from_definition(&def).unwrap();
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<CropDefinition>>()
+            .add(def);
+        let crop = app
+            .world_mut()
+            .spawn((SpatialBundle::default(), bundle, CropHandle(handle.clone())))
+            .id();
+        app.update();
+        pass_days(&mut app, 1);
+        assert!(app.world().get::<CropFruit>(crop).is_some());
+
+        let mut plain = stage(3);
+        plain.begin_status = None;
+        app.world_mut()
+            .resource_mut::<Assets<CropDefinition>>()
+            .get_mut(&handle)
+            .unwrap()
+            .stages = vec![plain];
+        // one frame to send the modified event, one to read it
+        app.update();
+        app.update();
+
+        assert_eq!(index_of(&app, crop), Some(0));
+        assert_eq!(
+            app.world().get::<CropStatus>(crop),
+            Some(&CropStatus::Growing)
+        );
+        assert!(app.world().get::<CropFruit>(crop).is_none());
+        assert_eq!(app.world().get::<CropTimer>(crop).map(|t| t.0), Some(3));
+    }
+}
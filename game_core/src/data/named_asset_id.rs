@@ -1,10 +1,20 @@
-use bevy::{prelude::*, utils::HashMap};
+use bevy::{
+    asset::{AssetPath, LoadedFolder},
+    prelude::*,
+    utils::HashMap,
+};
 
-#[derive(Resource, Default)]
+#[derive(Resource)]
 pub struct NamedAssets<T: Asset> {
     cache: HashMap<String, Handle<T>>,
 }
 
+impl<T: Asset> Default for NamedAssets<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T: Asset> NamedAssets<T> {
     pub fn new() -> Self {
         Self {
@@ -18,4 +28,105 @@ impl<T: Asset> NamedAssets<T> {
     pub fn get(&self, name: impl Into<String>) -> Option<Handle<T>> {
         self.cache.get(&name.into()).cloned()
     }
+    pub fn contains(&self, name: impl Into<String>) -> bool {
+        self.cache.contains_key(&name.into())
+    }
+    pub fn names(&self) -> impl Iterator<Item = &String> {
+        self.cache.keys()
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Handle<T>)> {
+        self.cache.iter()
+    }
+}
+
+/// Every `T` in an asset folder, indexed by a name read out of each asset (usually its id) once
+/// the whole folder has loaded
+pub struct FolderRegistry<T: Asset> {
+    folder: Handle<LoadedFolder>,
+    assets: NamedAssets<T>,
+    loaded: bool,
+}
+
+impl<T: Asset> Default for FolderRegistry<T> {
+    fn default() -> Self {
+        Self {
+            folder: Default::default(),
+            assets: NamedAssets::new(),
+            loaded: false,
+        }
+    }
+}
+
+impl<T: Asset> FolderRegistry<T> {
+    /// Starts loading the folder, see [FolderRegistry::loaded_folder] for when it's ready
+    pub fn scan(&mut self, assets: &AssetServer, path: impl Into<AssetPath<'static>>) {
+        self.folder = assets.load_folder(path);
+    }
+
+    /// Reads through the folder events, returning the folder if it has finished loading along
+    /// with everything in it
+    pub fn loaded_folder<'a>(
+        &self, events: &mut EventReader<AssetEvent<LoadedFolder>>,
+        folders: &'a Assets<LoadedFolder>,
+    ) -> Option<&'a LoadedFolder> {
+        let mut loaded = false;
+        for event in events.read() {
+            loaded |= event.is_loaded_with_dependencies(self.folder.id());
+        }
+        if !loaded {
+            return None;
+        }
+        folders.get(self.folder.id())
+    }
+
+    /// Replaces the index with every `T` in `folder`, named by `key`. Other asset types are
+    /// allowed to live alongside and are skipped. `kind` is only used for logging
+    pub fn index(
+        &mut self, folder: &LoadedFolder, assets: &Assets<T>, kind: &str,
+        key: impl Fn(&T) -> String,
+    ) {
+        let mut indexed = NamedAssets::<T>::new();
+        for handle in folder.handles.iter() {
+            let Ok(handle) = handle.clone().try_typed::<T>() else {
+                continue;
+            };
+            let Some(asset) = assets.get(handle.id()) else {
+                continue;
+            };
+            let name = key(asset);
+            if let Some(existing) = indexed.register(name.clone(), handle.clone()) {
+                error!(
+                    "Duplicate {} id \"{}\" in {:?} and {:?}",
+                    kind,
+                    name,
+                    existing.path(),
+                    handle.path()
+                );
+            }
+        }
+        info!("Indexed {} {} definitions", indexed.names().count(), kind);
+        self.assets = indexed;
+        self.loaded = true;
+    }
+
+    pub fn get(&self, name: impl Into<String>) -> Option<Handle<T>> {
+        self.assets.get(name)
+    }
+
+    pub fn contains(&self, name: impl Into<String>) -> bool {
+        self.assets.contains(name)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &String> {
+        self.assets.names()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Handle<T>)> {
+        self.assets.iter()
+    }
+
+    /// Whether the folder has finished loading and been indexed
+    pub fn is_loaded(&self) -> bool {
+        self.loaded
+    }
 }
@@ -1,37 +1,370 @@
-use bevy::prelude::*;
+use std::fmt;
 
-use crate::common_events::NewDay;
+use bevy::{prelude::*, utils::HashSet};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common_events::{NewDay, NewSeason, NewYear, PlayerPassedOut, PlayerSlept},
+    mouse::MouseState,
+};
 
 pub struct DaysPlugin;
 
-const DEBUG_SECONDS_PER_DAY: f32 = 45.0;
+const DEFAULT_DAYS_PER_SEASON: u32 = 28;
 
 impl Plugin for DaysPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(DayInfo {
-            timer: Timer::from_seconds(DEBUG_SECONDS_PER_DAY, TimerMode::Repeating),
-        });
-        app.add_systems(Update, (inc_days_timed, debug_inc_days_keypress));
+        app.init_resource::<TimeOfDay>();
+        app.init_resource::<GameClock>();
+        app.insert_resource(Calendar::new(DEFAULT_DAYS_PER_SEASON));
+        app.add_systems(Update, (pause_while_mouse_free, tick_clock).chain());
+        app.add_systems(Update, debug_inc_days_keypress);
+        if cfg!(debug_assertions) {
+            app.add_systems(Update, debug_time_scale_keypress);
+        }
+        app.observe(sleep_through_night);
+    }
+}
+
+/// The in-game clock, measured in minutes since midnight. The clock keeps running past midnight
+/// (e.g. 25:30) until it hits the end of the day
+#[derive(Resource, Clone, Debug)]
+pub struct TimeOfDay {
+    pub minutes: f32,
+    /// In-game minutes that pass per real second
+    pub minutes_per_second: f32,
+    pub day_start_hour: f32,
+    /// The hour the day is forced to end at, can be past 24
+    pub day_end_hour: f32,
+}
+
+impl Default for TimeOfDay {
+    fn default() -> Self {
+        Self {
+            minutes: 6.0 * 60.0,
+            minutes_per_second: 2.0,
+            day_start_hour: 6.0,
+            day_end_hour: 26.0,
+        }
+    }
+}
+
+impl TimeOfDay {
+    pub fn hours(&self) -> f32 {
+        self.minutes / 60.0
+    }
+
+    /// How far through the day we are, from 0 at the start of the day to 1 at the end
+    pub fn day_progress(&self) -> f32 {
+        ((self.hours() - self.day_start_hour) / (self.day_end_hour - self.day_start_hour))
+            .clamp(0.0, 1.0)
+    }
+
+    pub fn is_day_over(&self) -> bool {
+        self.hours() >= self.day_end_hour
+    }
+
+    /// Winds the clock back to the start of the day
+    pub fn start_day(&mut self) {
+        self.minutes = self.day_start_hour * 60.0;
+    }
+}
+
+impl fmt::Display for TimeOfDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let minutes = self.minutes as u32;
+        write!(f, "{:02}:{:02}", minutes / 60, minutes % 60)
+    }
+}
+
+/// Why the game clock is currently paused. The clock only runs again once every reason is cleared
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PauseReason {
+    /// Paused by hand, e.g. the debug pause key
+    Manual,
+    /// The mouse has been freed, which means some menu or screen is up
+    MouseFree,
+    /// The world inspector is open
+    Inspector,
+}
+
+/// Controls how fast game time passes compared to real time. Only game systems (the day clock and
+/// anything counting in-game minutes) read this, physics and UI keep running on [Time]
+#[derive(Resource, Clone, Debug)]
+pub struct GameClock {
+    /// Multiplier applied to real time while unpaused
+    pub scale: f32,
+    paused: HashSet<PauseReason>,
+}
+
+impl Default for GameClock {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            paused: HashSet::new(),
+        }
+    }
+}
+
+impl GameClock {
+    pub const MIN_SCALE: f32 = 0.25;
+    pub const MAX_SCALE: f32 = 64.0;
+
+    pub fn is_paused(&self) -> bool {
+        !self.paused.is_empty()
+    }
+
+    pub fn set_paused(&mut self, reason: PauseReason, paused: bool) {
+        if paused {
+            self.paused.insert(reason);
+        } else {
+            self.paused.remove(&reason);
+        }
+    }
+
+    pub fn toggle_paused(&mut self, reason: PauseReason) {
+        let paused = !self.paused.contains(&reason);
+        self.set_paused(reason, paused);
+    }
+
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale.clamp(Self::MIN_SCALE, Self::MAX_SCALE);
+    }
+
+    /// Seconds of game time that passed this frame, given the real frame time
+    pub fn delta_seconds(&self, time: &Time) -> f32 {
+        if self.is_paused() {
+            return 0.0;
+        }
+        time.delta_seconds() * self.scale
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+pub enum Season {
+    #[default]
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    pub fn next(self) -> Self {
+        match self {
+            Season::Spring => Season::Summer,
+            Season::Summer => Season::Autumn,
+            Season::Autumn => Season::Winter,
+            Season::Winter => Season::Spring,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+pub enum Weekday {
+    #[default]
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    pub fn next(self) -> Self {
+        match self {
+            Weekday::Monday => Weekday::Tuesday,
+            Weekday::Tuesday => Weekday::Wednesday,
+            Weekday::Wednesday => Weekday::Thursday,
+            Weekday::Thursday => Weekday::Friday,
+            Weekday::Friday => Weekday::Saturday,
+            Weekday::Saturday => Weekday::Sunday,
+            Weekday::Sunday => Weekday::Monday,
+        }
+    }
+}
+
+/// A single day on the in-game calendar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+pub struct Date {
+    /// Day of the season, starting from 1
+    pub day: u32,
+    pub season: Season,
+    /// Starting from 1
+    pub year: u32,
+    pub weekday: Weekday,
+}
+
+impl Default for Date {
+    fn default() -> Self {
+        Self {
+            day: 1,
+            season: Season::default(),
+            year: 1,
+            weekday: Weekday::default(),
+        }
     }
 }
 
+/// The in-game calendar, advanced by the [DaysPlugin] each time the day rolls over
 #[derive(Resource, Clone, Debug)]
-struct DayInfo {
-    timer: Timer,
+pub struct Calendar {
+    /// Day of the current season, starting from 1
+    pub day: u32,
+    pub season: Season,
+    /// Starting from 1
+    pub year: u32,
+    pub weekday: Weekday,
+    pub days_per_season: u32,
 }
 
-fn inc_days_timed(mut cmd: Commands, mut day: ResMut<DayInfo>, time: Res<Time>) {
-    day.timer.tick(time.delta());
-    if day.timer.just_finished() {
-        cmd.trigger(NewDay);
+impl Calendar {
+    pub fn new(days_per_season: u32) -> Self {
+        let date = Date::default();
+        Self {
+            day: date.day,
+            season: date.season,
+            year: date.year,
+            weekday: date.weekday,
+            days_per_season: days_per_season.max(1),
+        }
+    }
+
+    pub fn date(&self) -> Date {
+        Date {
+            day: self.day,
+            season: self.season,
+            year: self.year,
+            weekday: self.weekday,
+        }
+    }
+
+    /// Moves onto the next day, rolling over into the next season and year as needed
+    pub fn advance(&mut self) -> Date {
+        self.weekday = self.weekday.next();
+        self.day += 1;
+        if self.day > self.days_per_season {
+            self.day = 1;
+            self.season = self.season.next();
+            if self.season == Season::default() {
+                self.year += 1;
+            }
+        }
+        self.date()
+    }
+}
+
+fn advance_day(cmd: &mut Commands, calendar: &mut Calendar) {
+    let previous = calendar.date();
+    let date = calendar.advance();
+    cmd.trigger(NewDay(date));
+    if date.season != previous.season {
+        cmd.trigger(NewSeason(date.season));
+    }
+    if date.year != previous.year {
+        cmd.trigger(NewYear(date.year));
+    }
+}
+
+fn tick_clock(
+    mut cmd: Commands, mut clock: ResMut<TimeOfDay>, game_clock: Res<GameClock>, time: Res<Time>,
+) {
+    if clock.is_day_over() {
+        // stay put until the player has been put to bed
+        return;
+    }
+    clock.minutes += game_clock.delta_seconds(&time) * clock.minutes_per_second;
+    if clock.is_day_over() {
+        clock.minutes = clock.day_end_hour * 60.0;
+        cmd.trigger(PlayerPassedOut);
     }
 }
+
+/// However the player ended up in bed, the day is over once they're asleep
+fn sleep_through_night(
+    _: Trigger<PlayerSlept>, mut cmd: Commands, mut clock: ResMut<TimeOfDay>,
+    mut calendar: ResMut<Calendar>,
+) {
+    clock.start_day();
+    advance_day(&mut cmd, &mut calendar);
+}
+
 fn debug_inc_days_keypress(
-    mut cmd: Commands, mut day: ResMut<DayInfo>, input: Res<ButtonInput<KeyCode>>,
+    mut cmd: Commands, mut clock: ResMut<TimeOfDay>, mut calendar: ResMut<Calendar>,
+    input: Res<ButtonInput<KeyCode>>,
 ) {
     if !input.just_pressed(KeyCode::NumpadEnter) {
         return;
     }
-    day.timer.reset();
-    cmd.trigger(NewDay);
+    clock.start_day();
+    advance_day(&mut cmd, &mut calendar);
+}
+
+/// Any screen that frees the mouse is treated as a menu, so game time stops while it's open
+fn pause_while_mouse_free(mut game_clock: ResMut<GameClock>, mouse: Res<State<MouseState>>) {
+    if !mouse.is_changed() {
+        return;
+    }
+    game_clock.set_paused(PauseReason::MouseFree, *mouse.get() == MouseState::Free);
+}
+
+/// `F5` pauses, `[` and `]` halve and double the time scale, `\` resets it
+fn debug_time_scale_keypress(mut game_clock: ResMut<GameClock>, input: Res<ButtonInput<KeyCode>>) {
+    if input.just_pressed(KeyCode::F5) {
+        game_clock.toggle_paused(PauseReason::Manual);
+        info!("Game clock paused: {}", game_clock.is_paused());
+    }
+    let scale = if input.just_pressed(KeyCode::BracketRight) {
+        game_clock.scale * 2.0
+    } else if input.just_pressed(KeyCode::BracketLeft) {
+        game_clock.scale * 0.5
+    } else if input.just_pressed(KeyCode::Backslash) {
+        1.0
+    } else {
+        return;
+    };
+    game_clock.set_scale(scale);
+    info!("Game clock scale set to {}x", game_clock.scale);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calendar_rolls_over_seasons_and_years() {
+        let mut calendar = Calendar::new(2);
+        assert_eq!(calendar.date(), Date::default());
+
+        let date = calendar.advance();
+        assert_eq!((date.day, date.season, date.year), (2, Season::Spring, 1));
+        assert_eq!(date.weekday, Weekday::Tuesday);
+
+        let date = calendar.advance();
+        assert_eq!((date.day, date.season, date.year), (1, Season::Summer, 1));
+
+        for _ in 0..6 {
+            calendar.advance();
+        }
+        let date = calendar.date();
+        assert_eq!((date.day, date.season, date.year), (1, Season::Spring, 2));
+        // 8 days on from a monday
+        assert_eq!(date.weekday, Weekday::Tuesday);
+    }
+
+    #[test]
+    fn game_clock_stays_paused_until_every_reason_clears() {
+        let mut clock = GameClock::default();
+        clock.set_paused(PauseReason::MouseFree, true);
+        clock.set_paused(PauseReason::Inspector, true);
+        clock.set_paused(PauseReason::MouseFree, false);
+        assert!(clock.is_paused());
+        clock.toggle_paused(PauseReason::Inspector);
+        assert!(!clock.is_paused());
+
+        clock.set_scale(1000.0);
+        assert_eq!(clock.scale, GameClock::MAX_SCALE);
+    }
 }
@@ -0,0 +1,133 @@
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::ActionState;
+
+use crate::{
+    common_events::PlayerSlept,
+    items::{
+        inventory::{HotbarSelection, Inventory},
+        registry::ItemDefinitions,
+    },
+    mouse::MouseState,
+    player::{InputActions, PlayerMarker},
+};
+
+pub struct EnergyPlugin;
+
+impl Plugin for EnergyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, eat_food.run_if(in_state(MouseState::Locked)));
+        app.observe(restore_after_sleep);
+    }
+}
+
+/// Energy the player wakes up with after a full night's sleep
+pub const PLAYER_MAX_ENERGY: f32 = 100.0;
+/// Energy drained per second while sprinting
+pub const SPRINT_ENERGY_PER_SECOND: f32 = 2.0;
+
+/// How much the player has left in them for the day. Tools and sprinting use it up, and once it
+/// runs out the player is exhausted
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Energy {
+    pub current: f32,
+    pub max: f32,
+}
+
+impl Energy {
+    pub fn new(max: f32) -> Self {
+        Self { current: max, max }
+    }
+
+    /// Exhausted players walk slower and can't use tools
+    pub fn is_exhausted(&self) -> bool {
+        self.current <= 0.0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.current >= self.max
+    }
+
+    /// How full the meter is, between 0 and 1
+    pub fn fraction(&self) -> f32 {
+        if self.max <= 0.0 {
+            return 0.0;
+        }
+        (self.current / self.max).clamp(0.0, 1.0)
+    }
+
+    /// Uses up some energy, returns false without spending anything if already exhausted. The last
+    /// action before exhaustion is always allowed, even if it costs more than what's left
+    pub fn spend(&mut self, amount: f32) -> bool {
+        if self.is_exhausted() {
+            return false;
+        }
+        self.current = (self.current - amount).max(0.0);
+        true
+    }
+
+    pub fn restore(&mut self, amount: f32) {
+        self.current = (self.current + amount).min(self.max);
+    }
+}
+
+/// A good night's sleep fills the meter, passing out only gets the player halfway
+fn restore_after_sleep(trigger: Trigger<PlayerSlept>, mut query: Query<&mut Energy>) {
+    for mut energy in query.iter_mut() {
+        energy.current = if trigger.event().passed_out {
+            energy.max * 0.5
+        } else {
+            energy.max
+        };
+    }
+}
+
+/// Eats the food in the selected hotbar slot, unless the player is already full of energy
+fn eat_food(
+    mut player_query: Query<
+        (
+            &ActionState<InputActions>,
+            &mut Inventory,
+            &HotbarSelection,
+            &mut Energy,
+        ),
+        With<PlayerMarker>,
+    >,
+    items: ItemDefinitions,
+) {
+    let Ok((input, mut inventory, selection, mut energy)) = player_query.get_single_mut() else {
+        return;
+    };
+    if !input.just_pressed(&InputActions::Primary) || energy.is_full() {
+        return;
+    }
+    let Some((name, restored)) = inventory
+        .slot(selection.0)
+        .and_then(|stack| items.get(&stack.id))
+        .and_then(|data| Some((data.display_name(), data.energy?)))
+    else {
+        return;
+    };
+    if inventory.take(selection.0, 1).is_some() {
+        energy.restore(restored as f32);
+        info!("Ate {}, restoring {} energy", name, restored);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exhaustion_refuses_further_spending() {
+        let mut energy = Energy::new(10.0);
+        assert!(energy.spend(4.0));
+        assert!(energy.spend(8.0));
+        assert_eq!(energy.current, 0.0);
+        assert!(energy.is_exhausted());
+        assert!(!energy.spend(1.0));
+
+        energy.restore(25.0);
+        assert!(energy.is_full());
+        assert_eq!(energy.fraction(), 1.0);
+    }
+}
@@ -0,0 +1,248 @@
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::{
+    common_events::{NewDay, ToolUsed},
+    crops::components::{CropData, CropHandle},
+    items::ToolKind,
+};
+
+pub struct FarmPlugin;
+
+impl Plugin for FarmPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FarmGrid>();
+        app.add_systems(Startup, create_farm);
+        app.add_systems(Update, (release_tiles, update_soil_visuals));
+        app.observe(dry_soil);
+        app.observe(work_soil);
+    }
+}
+
+/// World space size of a single soil tile (both X and Z)
+pub const TILE_SIZE: f32 = 2.0;
+/// Number of tiles along X and Z
+pub const FARM_SIZE: IVec2 = IVec2::new(12, 8);
+/// The corner of tile (0, 0), tiles extend along +X and +Z from here
+pub const FARM_ORIGIN: Vec3 = Vec3::new(-12., 0., 0.);
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum SoilState {
+    #[default]
+    Untilled,
+    Tilled,
+    Watered,
+}
+
+/// A single plot of farmable land. Each tile holds at most one crop
+#[derive(Component, Debug, Clone)]
+pub struct SoilTile {
+    pub coord: IVec2,
+    pub state: SoilState,
+    pub crop: Option<Entity>,
+}
+
+impl SoilTile {
+    /// Whether a crop can be planted here right now
+    pub fn can_plant(&self) -> bool {
+        self.state != SoilState::Untilled && self.crop.is_none()
+    }
+
+    /// Tills the soil, returns false if it was already tilled
+    pub fn till(&mut self) -> bool {
+        if self.state != SoilState::Untilled {
+            return false;
+        }
+        self.state = SoilState::Tilled;
+        true
+    }
+
+    /// Flattens tilled soil back out, returns false if there's a crop in the way
+    pub fn untill(&mut self) -> bool {
+        if self.state == SoilState::Untilled || self.crop.is_some() {
+            return false;
+        }
+        self.state = SoilState::Untilled;
+        true
+    }
+
+    /// Waters the soil, returns false if the soil isn't tilled or is already watered
+    pub fn water(&mut self) -> bool {
+        if self.state != SoilState::Tilled {
+            return false;
+        }
+        self.state = SoilState::Watered;
+        true
+    }
+}
+
+/// The soil tile a crop was planted into
+#[derive(Component, Debug, Clone, Copy)]
+pub struct PlantedIn(pub Entity);
+
+/// Lookup from grid coordinates to [SoilTile] entities
+#[derive(Resource, Default, Debug)]
+pub struct FarmGrid {
+    tiles: HashMap<IVec2, Entity>,
+}
+
+impl FarmGrid {
+    pub fn tile(&self, coord: IVec2) -> Option<Entity> {
+        self.tiles.get(&coord).copied()
+    }
+
+    /// Finds the tile underneath a world position, if there is one
+    pub fn tile_at(&self, position: Vec3) -> Option<Entity> {
+        self.tile(Self::coord_of(position))
+    }
+
+    pub fn coord_of(position: Vec3) -> IVec2 {
+        let local = (position - FARM_ORIGIN) / TILE_SIZE;
+        IVec2::new(local.x.floor() as i32, local.z.floor() as i32)
+    }
+
+    /// The world position at the center of a tile, which is where crops get planted
+    pub fn tile_center(coord: IVec2) -> Vec3 {
+        FARM_ORIGIN + Vec3::new(coord.x as f32 + 0.5, 0., coord.y as f32 + 0.5) * TILE_SIZE
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&IVec2, &Entity)> {
+        self.tiles.iter()
+    }
+}
+
+#[derive(Resource)]
+struct SoilMaterials {
+    tilled: Handle<StandardMaterial>,
+    watered: Handle<StandardMaterial>,
+}
+
+pub fn create_farm(
+    mut cmd: Commands, mut grid: ResMut<FarmGrid>, mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let soil = SoilMaterials {
+        tilled: materials.add(StandardMaterial {
+            base_color: Color::srgb(0.35, 0.22, 0.12),
+            perceptual_roughness: 1.0,
+            ..default()
+        }),
+        watered: materials.add(StandardMaterial {
+            base_color: Color::srgb(0.18, 0.11, 0.06),
+            perceptual_roughness: 0.6,
+            ..default()
+        }),
+    };
+    // slightly smaller than the tile so the grid is readable
+    let mesh = meshes.add(Plane3d::new(Vec3::Y, Vec2::ONE * TILE_SIZE * 0.45));
+
+    for x in 0..FARM_SIZE.x {
+        for z in 0..FARM_SIZE.y {
+            let coord = IVec2::new(x, z);
+            let tile = cmd
+                .spawn((
+                    Name::new(format!("Soil {},{}", x, z)),
+                    SoilTile {
+                        coord,
+                        state: SoilState::Untilled,
+                        crop: None,
+                    },
+                    PbrBundle {
+                        mesh: mesh.clone(),
+                        material: soil.tilled.clone(),
+                        // lift just above the ground plane to avoid z-fighting
+                        transform: Transform::from_translation(
+                            FarmGrid::tile_center(coord) + Vec3::Y * 0.01,
+                        ),
+                        visibility: Visibility::Hidden,
+                        ..default()
+                    },
+                ))
+                .id();
+            grid.tiles.insert(coord, tile);
+        }
+    }
+    cmd.insert_resource(soil);
+}
+
+/// Frees up tiles whose crop has been despawned
+fn release_tiles(
+    mut tiles: Query<&mut SoilTile>, crops: Query<(), Or<(With<CropData>, With<CropHandle>)>>,
+) {
+    for mut tile in tiles.iter_mut() {
+        let Some(crop) = tile.crop else {
+            continue;
+        };
+        if !crops.contains(crop) {
+            tile.crop = None;
+        }
+    }
+}
+
+/// Watered soil dries out overnight. This goes through commands so that it lands after every other
+/// [NewDay] observer, which lets crops see whether they were watered during the day
+pub(crate) fn dry_soil(_: Trigger<NewDay>, query: Query<(&SoilTile, Entity)>, mut cmd: Commands) {
+    for (tile, entity) in query.iter() {
+        if tile.state != SoilState::Watered {
+            continue;
+        }
+        cmd.add(move |world: &mut World| {
+            if let Some(mut tile) = world.get_mut::<SoilTile>(entity) {
+                tile.state = SoilState::Tilled;
+            }
+        });
+    }
+}
+
+/// Hoes and watering cans work on the tile under the crop being aimed at, or else the tile under
+/// the aim point
+fn work_soil(
+    trigger: Trigger<ToolUsed>, grid: Res<FarmGrid>, mut tiles: Query<&mut SoilTile>,
+    planted: Query<&PlantedIn>,
+) {
+    let ToolUsed {
+        tool,
+        secondary,
+        point,
+    } = trigger.event();
+    let tile = match planted.get(trigger.entity()) {
+        Ok(planted) => Some(planted.0),
+        Err(_) => point.and_then(|point| grid.tile_at(point)),
+    };
+    let Some(mut tile) = tile.and_then(|tile| tiles.get_mut(tile).ok()) else {
+        return;
+    };
+    let worked = match (tool, secondary) {
+        (ToolKind::Hoe, false) => tile.till(),
+        (ToolKind::Hoe, true) => tile.untill(),
+        (ToolKind::WateringCan, false) => tile.water(),
+        _ => return,
+    };
+    if !worked {
+        debug!("{:?} did nothing to soil {}", tool, tile.coord);
+    }
+}
+
+fn update_soil_visuals(
+    mut query: Query<
+        (&SoilTile, &mut Handle<StandardMaterial>, &mut Visibility),
+        Changed<SoilTile>,
+    >,
+    soil: Option<Res<SoilMaterials>>,
+) {
+    let Some(soil) = soil else {
+        return;
+    };
+    for (tile, mut material, mut visibility) in query.iter_mut() {
+        match tile.state {
+            SoilState::Untilled => *visibility = Visibility::Hidden,
+            SoilState::Tilled => {
+                *material = soil.tilled.clone();
+                *visibility = Visibility::Inherited;
+            }
+            SoilState::Watered => {
+                *material = soil.watered.clone();
+                *visibility = Visibility::Inherited;
+            }
+        }
+    }
+}
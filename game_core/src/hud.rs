@@ -1,19 +1,78 @@
 use bevy::prelude::*;
 
-use crate::common_events::PlayerInteractionChanged;
+use crate::{
+    common_events::{DayEarnings, PlayerInteractionChanged},
+    energy::Energy,
+    items::{
+        inventory::{HotbarSelection, Inventory, HOTBAR_SLOTS},
+        registry::ItemRegistry,
+    },
+    player::PlayerMarker,
+    shipping::Wallet,
+    weather::Forecast,
+};
 
 pub struct HudPlugin;
 
 impl Plugin for HudPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(Startup, create_hud);
+        app.add_systems(
+            Update,
+            (
+                update_forecast_label,
+                update_hotbar,
+                update_item_slots,
+                update_energy_bar,
+                update_wallet_label,
+                hide_earnings_label,
+            ),
+        );
         app.observe(update_interact_label);
+        app.observe(show_earnings);
     }
 }
 
 #[derive(Component)]
 struct InteractNameLabel;
 
+#[derive(Component)]
+struct ForecastLabel;
+
+#[derive(Component)]
+struct WalletLabel;
+
+/// Shows what the shipping bin sold overnight, hidden again once the timer runs out
+#[derive(Component)]
+struct EarningsLabel(Timer);
+
+/// A single hotbar slot, holding the index of the inventory slot it shows
+#[derive(Component)]
+struct HotbarSlot(usize);
+
+/// Shows the icon of the item in an inventory slot
+#[derive(Component)]
+pub(crate) struct SlotIcon(pub usize);
+
+/// Shows the stack size of an inventory slot
+#[derive(Component)]
+pub(crate) struct SlotCount(pub usize);
+
+/// The filled part of the energy meter
+#[derive(Component)]
+struct EnergyBarFill;
+
+const HOTBAR_SLOT_SIZE: f32 = 56.;
+const HOTBAR_SLOT_COLOR: Color = Color::srgba(0.1, 0.1, 0.12, 0.7);
+const HOTBAR_SELECTED_BORDER: Color = Color::srgb(1.0, 0.85, 0.3);
+const HOTBAR_BORDER: Color = Color::srgba(0.0, 0.0, 0.0, 0.0);
+const EARNINGS_SECONDS: f32 = 6.;
+const ENERGY_BAR_HEIGHT: f32 = 160.;
+const ENERGY_COLOR: Color = Color::srgb(0.35, 0.8, 0.3);
+const ENERGY_LOW_COLOR: Color = Color::srgb(0.9, 0.25, 0.2);
+/// Below this fraction the energy bar turns red
+const ENERGY_LOW_FRACTION: f32 = 0.25;
+
 fn create_hud(mut cmd: Commands) {
     // cmd.spawn(UI)
     cmd.spawn((
@@ -27,6 +86,139 @@ fn create_hud(mut cmd: Commands) {
             ..default()
         }),
     ));
+    cmd.spawn((
+        Name::new("Forecast Label"),
+        ForecastLabel,
+        TextBundle::from_section("---", TextStyle::default()).with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(64.),
+            right: Val::Px(32.),
+            ..default()
+        }),
+    ));
+    cmd.spawn((
+        Name::new("Wallet Label"),
+        WalletLabel,
+        TextBundle::from_section("0g", TextStyle::default()).with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(96.),
+            right: Val::Px(32.),
+            ..default()
+        }),
+    ));
+    cmd.spawn((
+        Name::new("Earnings Label"),
+        EarningsLabel(Timer::from_seconds(EARNINGS_SECONDS, TimerMode::Once)),
+        TextBundle {
+            text: Text::from_section("", TextStyle::default()),
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(128.),
+                right: Val::Px(32.),
+                ..default()
+            },
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+    ));
+    cmd.spawn((
+        Name::new("Hotbar"),
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(16.),
+                width: Val::Percent(100.),
+                justify_content: JustifyContent::Center,
+                column_gap: Val::Px(4.),
+                ..default()
+            },
+            ..default()
+        },
+    ))
+    .with_children(|b| {
+        for index in 0..HOTBAR_SLOTS {
+            b.spawn((
+                HotbarSlot(index),
+                NodeBundle {
+                    style: Style {
+                        width: Val::Px(HOTBAR_SLOT_SIZE),
+                        height: Val::Px(HOTBAR_SLOT_SIZE),
+                        border: UiRect::all(Val::Px(3.)),
+                        padding: UiRect::all(Val::Px(4.)),
+                        ..default()
+                    },
+                    background_color: HOTBAR_SLOT_COLOR.into(),
+                    border_color: HOTBAR_BORDER.into(),
+                    ..default()
+                },
+            ))
+            .with_children(|b| spawn_slot_contents(b, index));
+        }
+    });
+    cmd.spawn((
+        Name::new("Energy Bar"),
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(16.),
+                right: Val::Px(32.),
+                width: Val::Px(20.),
+                height: Val::Px(ENERGY_BAR_HEIGHT),
+                border: UiRect::all(Val::Px(3.)),
+                // fill from the bottom up
+                flex_direction: FlexDirection::ColumnReverse,
+                ..default()
+            },
+            background_color: HOTBAR_SLOT_COLOR.into(),
+            ..default()
+        },
+    ))
+    .with_children(|b| {
+        b.spawn((
+            EnergyBarFill,
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    ..default()
+                },
+                background_color: ENERGY_COLOR.into(),
+                ..default()
+            },
+        ));
+    });
+}
+
+/// Spawns the icon and stack count for a UI slot mirroring one of the player's inventory slots
+pub(crate) fn spawn_slot_contents(b: &mut ChildBuilder, index: usize) {
+    b.spawn((
+        SlotIcon(index),
+        ImageBundle {
+            style: Style {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                ..default()
+            },
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+    ));
+    b.spawn((
+        SlotCount(index),
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 16.,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            right: Val::Px(4.),
+            bottom: Val::Px(2.),
+            ..default()
+        }),
+    ));
 }
 
 fn update_interact_label(
@@ -49,3 +241,125 @@ fn update_interact_label(
     };
     label.sections[0].value = name.into();
 }
+
+fn update_forecast_label(
+    forecast: Res<Forecast>, mut query: Query<&mut Text, With<ForecastLabel>>,
+) {
+    if !forecast.is_changed() {
+        return;
+    }
+    let Ok(mut label) = query.get_single_mut() else {
+        warn!("Couldn't find HUD forecast label");
+        return;
+    };
+    label.sections[0].value = format!("{} (tomorrow: {})", forecast.today, forecast.tomorrow);
+}
+
+fn update_hotbar(
+    player_query: Query<&HotbarSelection, (With<PlayerMarker>, Changed<HotbarSelection>)>,
+    mut slot_query: Query<(&HotbarSlot, &mut BorderColor)>,
+) {
+    let Ok(selection) = player_query.get_single() else {
+        return;
+    };
+    for (slot, mut border) in slot_query.iter_mut() {
+        let color = if slot.0 == selection.0 {
+            HOTBAR_SELECTED_BORDER
+        } else {
+            HOTBAR_BORDER
+        };
+        border.set_if_neq(BorderColor(color));
+    }
+}
+
+/// Mirrors the player's inventory into every [SlotIcon] and [SlotCount]. Runs every frame since
+/// the registry can finish indexing well after the inventory last changed
+fn update_item_slots(
+    player_query: Query<&Inventory, With<PlayerMarker>>,
+    mut icon_query: Query<(&SlotIcon, &mut UiImage, &mut Visibility)>,
+    mut count_query: Query<(&SlotCount, &mut Text)>, registry: Res<ItemRegistry>,
+) {
+    let Ok(inventory) = player_query.get_single() else {
+        return;
+    };
+    for (icon, mut image, mut visibility) in icon_query.iter_mut() {
+        let texture = inventory
+            .slot(icon.0)
+            .and_then(|stack| registry.icon(&stack.id));
+        let Some(texture) = texture else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        if image.texture != texture {
+            image.texture = texture;
+        }
+        visibility.set_if_neq(Visibility::Inherited);
+    }
+    for (count, mut text) in count_query.iter_mut() {
+        let label = match inventory.slot(count.0) {
+            Some(stack) if stack.amount > 1 => stack.amount.to_string(),
+            _ => String::new(),
+        };
+        if text.sections[0].value != label {
+            text.sections[0].value = label;
+        }
+    }
+}
+
+fn update_energy_bar(
+    player_query: Query<&Energy, (With<PlayerMarker>, Changed<Energy>)>,
+    mut fill_query: Query<(&mut Style, &mut BackgroundColor), With<EnergyBarFill>>,
+) {
+    let Ok(energy) = player_query.get_single() else {
+        return;
+    };
+    let Ok((mut style, mut color)) = fill_query.get_single_mut() else {
+        warn!("Couldn't find HUD energy bar");
+        return;
+    };
+    let fraction = energy.fraction();
+    style.height = Val::Percent(fraction * 100.);
+    let target = if fraction < ENERGY_LOW_FRACTION {
+        ENERGY_LOW_COLOR
+    } else {
+        ENERGY_COLOR
+    };
+    color.set_if_neq(BackgroundColor(target));
+}
+
+fn update_wallet_label(wallet: Res<Wallet>, mut query: Query<&mut Text, With<WalletLabel>>) {
+    if !wallet.is_changed() {
+        return;
+    }
+    let Ok(mut label) = query.get_single_mut() else {
+        warn!("Couldn't find HUD wallet label");
+        return;
+    };
+    label.sections[0].value = format!("{}g", wallet.0);
+}
+
+fn show_earnings(
+    trigger: Trigger<DayEarnings>,
+    mut query: Query<(&mut Text, &mut Visibility, &mut EarningsLabel)>,
+) {
+    let Ok((mut label, mut visibility, mut earnings)) = query.get_single_mut() else {
+        warn!("Couldn't find HUD earnings label");
+        return;
+    };
+    let event = trigger.event();
+    let amount: u32 = event.sold.iter().map(|item| item.amount).sum();
+    label.sections[0].value = format!("Shipped {} items for {}g", amount, event.total);
+    *visibility = Visibility::Inherited;
+    earnings.0.reset();
+}
+
+fn hide_earnings_label(mut query: Query<(&mut Visibility, &mut EarningsLabel)>, time: Res<Time>) {
+    for (mut visibility, mut earnings) in query.iter_mut() {
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+        if earnings.0.tick(time.delta()).just_finished() {
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
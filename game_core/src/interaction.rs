@@ -2,10 +2,13 @@ use avian3d::prelude::{
     Collider, ColliderConstructor, ColliderConstructorHierarchy, CollisionLayers, LayerMask,
     MassPropertiesBundle, RigidBody,
 };
-use bevy::prelude::*;
+use bevy::{ecs::system::EntityCommands, prelude::*};
 
 use crate::{
-    collision::GameLayers, common_events::PlayerInteract, data::game_asset_path::GameAssetPath,
+    collision::GameLayers,
+    common_events::{PlayerInteract, ToolUsed},
+    data::game_asset_path::GameAssetPath,
+    items::ToolKind,
 };
 
 pub struct InteractionPlugin;
@@ -45,9 +48,40 @@ fn spawn_interactable_crate(cmd: &mut Commands, scene: Handle<Scene>, position:
             ..default()
         },
     ))
-    .observe(handle_interact);
+    .observe(handle_interact)
+    .observe(chop_crate);
+}
+
+/// Spawns a prop that stays put and can be interacted with, colliding with the meshes of its scene
+pub fn spawn_interactable_prop<'a>(
+    cmd: &'a mut Commands, scene: Handle<Scene>, transform: Transform, name: &str,
+) -> EntityCommands<'a> {
+    cmd.spawn((
+        Name::new(name.to_string()),
+        RigidBody::Static,
+        Interactable,
+        CollisionLayers::new(
+            [GameLayers::Default, GameLayers::Interactable],
+            LayerMask::ALL,
+        ),
+        ColliderConstructorHierarchy::new(ColliderConstructor::ConvexHullFromMesh),
+        SceneBundle {
+            scene,
+            transform,
+            ..default()
+        },
+    ))
 }
 
 fn handle_interact(event: Trigger<PlayerInteract>) {
     info!("Received event at interactable root {}", event.entity());
 }
+
+/// The test crates are wooden, so they can be chopped apart
+fn chop_crate(event: Trigger<ToolUsed>, mut cmd: Commands) {
+    if event.event().tool != ToolKind::Axe || event.event().secondary {
+        return;
+    }
+    info!("Chopped crate {}", event.entity());
+    cmd.entity(event.entity()).despawn_recursive();
+}
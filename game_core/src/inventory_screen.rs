@@ -0,0 +1,447 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+use bevy_alt_ui_navigation_lite::{
+    prelude::{Focusable, Focused, NavEvent, NavEventReaderExt},
+    systems::InputMapping,
+};
+use leafwing_input_manager::prelude::ActionState;
+
+use crate::{
+    hud::spawn_slot_contents,
+    items::{
+        inventory::{Inventory, ItemStack, HOTBAR_SLOTS},
+        registry::ItemRegistry,
+        spawn_item, ItemData,
+    },
+    mouse::{MouseState, MouseStateRequest},
+    player::{InputActions, PlayerMarker},
+};
+
+pub struct InventoryScreenPlugin;
+
+impl Plugin for InventoryScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<InventoryScreen>();
+        app.init_resource::<HeldStack>();
+        app.add_systems(Startup, configure_navigation);
+        app.add_systems(OnEnter(InventoryScreen::Open), open_inventory_screen);
+        app.add_systems(OnExit(InventoryScreen::Open), close_inventory_screen);
+        app.add_systems(Update, toggle_inventory_screen);
+        app.add_systems(
+            Update,
+            (drag_slots, activate_slots, split_slots, update_held_stack)
+                .chain()
+                .run_if(in_state(InventoryScreen::Open)),
+        );
+    }
+}
+
+const SLOT_SIZE: f32 = 56.;
+const SLOT_COLOR: Color = Color::srgba(0.1, 0.1, 0.12, 0.9);
+const HOTBAR_ROW_COLOR: Color = Color::srgba(0.15, 0.13, 0.1, 0.9);
+const SLOTS_PER_ROW: usize = HOTBAR_SLOTS;
+
+#[derive(States, Debug, Hash, PartialEq, Eq, Clone, Default)]
+pub enum InventoryScreen {
+    #[default]
+    Closed,
+    Open,
+}
+
+/// The stack currently picked up with the cursor (or gamepad) while the inventory screen is open
+#[derive(Resource, Default, Debug)]
+struct HeldStack {
+    stack: Option<ItemStack>,
+    /// Slot the stack was first picked up from, so it can go back there when the screen closes
+    origin: Option<usize>,
+    /// Slot a mouse drag started from
+    dragging_from: Option<usize>,
+}
+
+impl HeldStack {
+    fn click(&mut self, inventory: &mut Inventory, index: usize) {
+        let had_stack = self.stack.is_some();
+        click_slot(inventory, &mut self.stack, index);
+        if !had_stack {
+            self.origin = Some(index);
+        }
+    }
+
+    fn split(&mut self, inventory: &mut Inventory, index: usize) {
+        let had_stack = self.stack.is_some();
+        split_slot(inventory, &mut self.stack, index);
+        if !had_stack {
+            self.origin = Some(index);
+        }
+    }
+}
+
+#[derive(Component)]
+struct InventoryScreenRoot;
+
+/// A clickable slot on the inventory screen, holding the inventory slot index it shows
+#[derive(Component)]
+struct InventorySlotButton(usize);
+
+#[derive(Component)]
+struct HeldStackLabel;
+
+#[derive(Component)]
+struct HeldStackIcon;
+
+/// Picks up the slot's stack when the hand is empty. Otherwise the held stack is dropped into the
+/// slot, merging with a matching stack or swapping places with a different one
+fn click_slot(inventory: &mut Inventory, held: &mut Option<ItemStack>, index: usize) {
+    let Some(stack) = held.take() else {
+        *held = inventory.take(index, u32::MAX);
+        return;
+    };
+    let Some(rest) = inventory.put(index, stack) else {
+        return;
+    };
+    let occupied_by_other = inventory
+        .slot(index)
+        .is_some_and(|existing| !existing.stacks_with(&rest));
+    if !occupied_by_other {
+        *held = Some(rest);
+        return;
+    }
+    *held = inventory.take(index, u32::MAX);
+    inventory.put(index, rest);
+}
+
+/// Picks up half the slot's stack when the hand is empty, otherwise drops a single item into it
+fn split_slot(inventory: &mut Inventory, held: &mut Option<ItemStack>, index: usize) {
+    let Some(stack) = held else {
+        let half = inventory
+            .slot(index)
+            .map_or(0, |stack| stack.amount.div_ceil(2));
+        *held = inventory.take(index, half);
+        return;
+    };
+    let one = ItemStack {
+        amount: 1,
+        ..stack.clone()
+    };
+    if inventory.put(index, one).is_some() {
+        return;
+    }
+    stack.amount -= 1;
+    if stack.amount == 0 {
+        *held = None;
+    }
+}
+
+fn configure_navigation(mut mapping: ResMut<InputMapping>) {
+    mapping.keyboard_navigation = true;
+    mapping.focus_follows_mouse = true;
+}
+
+fn toggle_inventory_screen(
+    query: Query<&ActionState<InputActions>, With<PlayerMarker>>,
+    screen: Res<State<InventoryScreen>>, mut next: ResMut<NextState<InventoryScreen>>,
+    mouse: Res<State<MouseState>>,
+) {
+    let Ok(input) = query.get_single() else {
+        return;
+    };
+    match screen.get() {
+        InventoryScreen::Closed => {
+            // anything else freeing the mouse is some other menu, which takes priority
+            if input.just_pressed(&InputActions::Inventory) && *mouse.get() == MouseState::Locked {
+                next.set(InventoryScreen::Open);
+            }
+        }
+        InventoryScreen::Open => {
+            if input.just_pressed(&InputActions::Inventory)
+                || input.just_pressed(&InputActions::Cancel)
+            {
+                next.set(InventoryScreen::Closed);
+            }
+        }
+    }
+}
+
+fn open_inventory_screen(
+    mut cmd: Commands, query: Query<&Inventory, With<PlayerMarker>>, mut held: ResMut<HeldStack>,
+) {
+    let Ok(inventory) = query.get_single() else {
+        warn!("Opened the inventory screen without a player inventory");
+        return;
+    };
+    *held = HeldStack::default();
+    cmd.trigger(MouseStateRequest(MouseState::Free));
+
+    let rows = inventory.slot_count().div_ceil(SLOTS_PER_ROW);
+    cmd.spawn((
+        Name::new("Inventory Screen"),
+        InventoryScreenRoot,
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(4.),
+                ..default()
+            },
+            background_color: Color::srgba(0., 0., 0., 0.6).into(),
+            ..default()
+        },
+    ))
+    .with_children(|b| {
+        b.spawn((
+            HeldStackLabel,
+            TextBundle::from_section("", TextStyle::default()),
+        ));
+        // the hotbar row goes last so it lines up with the hotbar at the bottom of the screen
+        for row in (0..rows).map(|row| (row + 1) % rows) {
+            b.spawn(NodeBundle {
+                style: Style {
+                    column_gap: Val::Px(4.),
+                    margin: UiRect::top(Val::Px(if row == 0 { 16. } else { 0. })),
+                    ..default()
+                },
+                ..default()
+            })
+            .with_children(|b| {
+                let start = row * SLOTS_PER_ROW;
+                for index in start..(start + SLOTS_PER_ROW).min(inventory.slot_count()) {
+                    spawn_slot_button(b, index, row == 0);
+                }
+            });
+        }
+        b.spawn((
+            HeldStackIcon,
+            ImageBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Px(SLOT_SIZE * 0.75),
+                    height: Val::Px(SLOT_SIZE * 0.75),
+                    ..default()
+                },
+                visibility: Visibility::Hidden,
+                z_index: ZIndex::Global(50),
+                ..default()
+            },
+        ));
+    });
+}
+
+fn spawn_slot_button(b: &mut ChildBuilder, index: usize, hotbar: bool) {
+    b.spawn((
+        InventorySlotButton(index),
+        Focusable::new(),
+        ButtonBundle {
+            style: Style {
+                width: Val::Px(SLOT_SIZE),
+                height: Val::Px(SLOT_SIZE),
+                border: UiRect::all(Val::Px(3.)),
+                padding: UiRect::all(Val::Px(4.)),
+                ..default()
+            },
+            background_color: if hotbar { HOTBAR_ROW_COLOR } else { SLOT_COLOR }.into(),
+            ..default()
+        },
+    ))
+    .with_children(|b| spawn_slot_contents(b, index));
+}
+
+/// Puts anything still held back into the inventory, dropping it on the ground if there's no room
+fn close_inventory_screen(
+    mut cmd: Commands, root_query: Query<Entity, With<InventoryScreenRoot>>,
+    mut player_query: Query<(&mut Inventory, &GlobalTransform), With<PlayerMarker>>,
+    mut held: ResMut<HeldStack>, registry: Res<ItemRegistry>,
+) {
+    for entity in root_query.iter() {
+        cmd.entity(entity).despawn_recursive();
+    }
+    cmd.trigger(MouseStateRequest(MouseState::Locked));
+
+    let HeldStack { stack, origin, .. } = std::mem::take(&mut *held);
+    let Some(stack) = stack else {
+        return;
+    };
+    let Ok((mut inventory, trans)) = player_query.get_single_mut() else {
+        return;
+    };
+    let stack = match origin {
+        Some(origin) => inventory.put(origin, stack),
+        None => Some(stack),
+    };
+    let Some(stack) = stack else {
+        return;
+    };
+    let leftover = inventory.add_stack(stack.clone());
+    if leftover == 0 {
+        return;
+    }
+    let Some(item) = registry.item(&stack.id) else {
+        error!("Lost {} of unknown item \"{}\"", leftover, stack.id.0);
+        return;
+    };
+    for _ in 0..leftover {
+        let entity = spawn_item(
+            &mut cmd,
+            item.clone(),
+            trans.translation() + trans.forward() * 1.5,
+        );
+        cmd.entity(entity).insert(stack.quality);
+    }
+}
+
+/// Mouse drag and drop. Pressing on a slot picks it up, and releasing over a different slot drops
+/// it there. Releasing over the same slot keeps it in hand, so click-to-move works as well
+fn drag_slots(
+    mouse: Res<ButtonInput<MouseButton>>, slot_query: Query<(&InventorySlotButton, &Interaction)>,
+    mut player_query: Query<&mut Inventory, With<PlayerMarker>>, mut held: ResMut<HeldStack>,
+) {
+    let Ok(mut inventory) = player_query.get_single_mut() else {
+        return;
+    };
+    let hovered = slot_query
+        .iter()
+        .find(|(_, interaction)| **interaction != Interaction::None)
+        .map(|(slot, _)| slot.0);
+    if mouse.just_pressed(MouseButton::Left) {
+        if let Some(index) = hovered {
+            held.click(&mut inventory, index);
+            held.dragging_from = Some(index);
+        }
+    }
+    if mouse.just_released(MouseButton::Left) {
+        let from = held.dragging_from.take();
+        if let Some(index) = hovered.filter(|index| Some(*index) != from) {
+            if held.stack.is_some() {
+                held.click(&mut inventory, index);
+            }
+        }
+    }
+}
+
+/// Keyboard and gamepad activation of the focused slot, through the navigation plugin
+fn activate_slots(
+    mut events: EventReader<NavEvent>, slot_query: Query<&InventorySlotButton>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut player_query: Query<&mut Inventory, With<PlayerMarker>>, mut held: ResMut<HeldStack>,
+) {
+    // mouse clicks also come through as navigation actions, but those are handled by [drag_slots]
+    if mouse.pressed(MouseButton::Left) || mouse.just_released(MouseButton::Left) {
+        events.clear();
+        return;
+    }
+    let Ok(mut inventory) = player_query.get_single_mut() else {
+        return;
+    };
+    for slot in events.nav_iter().activated_in_query(&slot_query) {
+        held.click(&mut inventory, slot.0);
+    }
+}
+
+/// Splits stacks with the secondary action, on the hovered slot or else the focused one
+fn split_slots(
+    input_query: Query<&ActionState<InputActions>, With<PlayerMarker>>,
+    slot_query: Query<(&InventorySlotButton, &Interaction)>,
+    focused_query: Query<&InventorySlotButton, With<Focused>>,
+    mut player_query: Query<&mut Inventory, With<PlayerMarker>>, mut held: ResMut<HeldStack>,
+) {
+    let Ok(input) = input_query.get_single() else {
+        return;
+    };
+    if !input.just_pressed(&InputActions::Secondary) {
+        return;
+    }
+    let Ok(mut inventory) = player_query.get_single_mut() else {
+        return;
+    };
+    let hovered = slot_query
+        .iter()
+        .find(|(_, interaction)| **interaction != Interaction::None)
+        .map(|(slot, _)| slot.0);
+    let Some(index) = hovered.or_else(|| focused_query.iter().next().map(|slot| slot.0)) else {
+        return;
+    };
+    held.split(&mut inventory, index);
+}
+
+/// Shows what's in hand, both as a label and as an icon following the cursor
+fn update_held_stack(
+    held: Res<HeldStack>, mut label_query: Query<&mut Text, With<HeldStackLabel>>,
+    mut icon_query: Query<(&mut UiImage, &mut Style, &mut Visibility), With<HeldStackIcon>>,
+    window_query: Query<&Window, With<PrimaryWindow>>, registry: Res<ItemRegistry>,
+    items: Res<Assets<ItemData>>,
+) {
+    let label = match &held.stack {
+        Some(stack) => {
+            let name = registry
+                .get(&stack.id)
+                .and_then(|handle| items.get(handle.id()))
+                .map_or(stack.id.0.as_str(), |data| data.display_name());
+            format!("Holding {} x{}", name, stack.amount)
+        }
+        None => String::new(),
+    };
+    for mut text in label_query.iter_mut() {
+        if text.sections[0].value != label {
+            text.sections[0].value = label.clone();
+        }
+    }
+
+    let cursor = window_query
+        .get_single()
+        .ok()
+        .and_then(|window| window.cursor_position());
+    let texture = held
+        .stack
+        .as_ref()
+        .and_then(|stack| registry.icon(&stack.id));
+    for (mut image, mut style, mut visibility) in icon_query.iter_mut() {
+        let (Some(texture), Some(cursor)) = (texture.clone(), cursor) else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        if image.texture != texture {
+            image.texture = texture;
+        }
+        style.left = Val::Px(cursor.x);
+        style.top = Val::Px(cursor.y);
+        visibility.set_if_neq(Visibility::Inherited);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::ItemId;
+
+    fn stack(id: &str, amount: u32) -> ItemStack {
+        ItemStack {
+            id: ItemId(id.into()),
+            amount,
+            max_stack: 10,
+            quality: default(),
+        }
+    }
+
+    #[test]
+    fn clicking_moves_splits_and_swaps_stacks() {
+        let mut inventory = Inventory::new(3);
+        inventory.put(0, stack("corn", 5));
+        inventory.put(1, stack("beets", 2));
+        let mut held = None;
+
+        split_slot(&mut inventory, &mut held, 0);
+        assert_eq!(held, Some(stack("corn", 3)));
+        split_slot(&mut inventory, &mut held, 2);
+        assert_eq!(inventory.slot(2), Some(&stack("corn", 1)));
+
+        click_slot(&mut inventory, &mut held, 1);
+        assert_eq!(held, Some(stack("beets", 2)));
+        assert_eq!(inventory.slot(1), Some(&stack("corn", 2)));
+
+        click_slot(&mut inventory, &mut held, 2);
+        assert_eq!(held, Some(stack("corn", 1)));
+        assert_eq!(inventory.slot(2), Some(&stack("beets", 2)));
+    }
+}
@@ -1,6 +1,7 @@
-use super::ItemId;
-use crate::data::range::Range;
-use bevy::prelude::*;
+use super::{registry::ItemRegistry, spawn_item, ItemId};
+use crate::{data::range::Range, Random};
+use bevy::{ecs::system::SystemParam, prelude::*};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
 #[derive(Reflect, Hash, Clone, Debug, Serialize, Deserialize, PartialEq)]
@@ -8,3 +9,37 @@ pub struct ItemDrop {
     pub item: ItemId,
     pub amount: Range,
 }
+
+/// How far from the origin dropped items can be scattered
+const DROP_SCATTER_RADIUS: f32 = 1.0;
+
+/// Everything needed to roll and spawn [ItemDrop]s. The random source is shared so systems that
+/// drop items can use it for other rolls as well
+#[derive(SystemParam)]
+pub struct DropSpawner<'w> {
+    pub rng: ResMut<'w, Random>,
+    registry: Res<'w, ItemRegistry>,
+}
+
+impl DropSpawner<'_> {
+    /// Rolls the amount for every drop and spawns that many items scattered around `origin`
+    pub fn spawn(&mut self, cmd: &mut Commands, drops: &[ItemDrop], origin: Vec3) {
+        for drop in drops {
+            let Some(item) = self.registry.item(&drop.item) else {
+                error!("Can't drop unknown item \"{}\"", drop.item.0);
+                continue;
+            };
+            let amount = drop.amount.get(&mut self.rng);
+            for _ in 0..amount {
+                let offset = Vec3::new(
+                    self.rng
+                        .gen_range(-DROP_SCATTER_RADIUS..=DROP_SCATTER_RADIUS),
+                    1.0,
+                    self.rng
+                        .gen_range(-DROP_SCATTER_RADIUS..=DROP_SCATTER_RADIUS),
+                );
+                spawn_item(cmd, item.clone(), origin + offset);
+            }
+        }
+    }
+}
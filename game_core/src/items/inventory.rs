@@ -0,0 +1,296 @@
+use bevy::prelude::*;
+
+use super::{ItemData, ItemId, ItemQuality};
+
+/// Number of slots in the player's inventory
+pub const PLAYER_INVENTORY_SLOTS: usize = 36;
+/// The first few inventory slots double as the hotbar
+pub const HOTBAR_SLOTS: usize = 9;
+
+/// Which hotbar slot is currently in hand
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct HotbarSelection(pub usize);
+
+/// A pile of the same item sitting in an inventory slot
+#[derive(Debug, Clone, PartialEq, Eq, Reflect)]
+pub struct ItemStack {
+    pub id: ItemId,
+    pub amount: u32,
+    /// Copied from the item's [ItemData] so stacks can be moved around without the asset
+    pub max_stack: u32,
+    /// Items of different quality never share a stack
+    pub quality: ItemQuality,
+}
+
+impl ItemStack {
+    pub fn new(item: &ItemData, amount: u32) -> Self {
+        Self {
+            id: item.id.clone(),
+            amount,
+            max_stack: item.max_stack.max(1),
+            quality: ItemQuality::Normal,
+        }
+    }
+
+    pub fn with_quality(mut self, quality: ItemQuality) -> Self {
+        self.quality = quality;
+        self
+    }
+
+    /// Whether the other stack can be merged into this one
+    pub fn stacks_with(&self, other: &ItemStack) -> bool {
+        self.id == other.id && self.quality == other.quality
+    }
+
+    /// How many more of this item fit on the stack
+    pub fn space(&self) -> u32 {
+        self.max_stack.saturating_sub(self.amount)
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.space() == 0
+    }
+}
+
+/// A fixed number of item slots, each holding at most one [ItemStack]
+#[derive(Component, Debug, Clone, Reflect)]
+pub struct Inventory {
+    slots: Vec<Option<ItemStack>>,
+}
+
+impl Inventory {
+    pub fn new(slots: usize) -> Self {
+        Self {
+            slots: vec![None; slots],
+        }
+    }
+
+    /// Number of slots, whether they hold anything or not
+    pub fn slot_count(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn has_no_items(&self) -> bool {
+        self.slots.iter().all(Option::is_none)
+    }
+
+    pub fn slot(&self, index: usize) -> Option<&ItemStack> {
+        self.slots.get(index)?.as_ref()
+    }
+
+    pub fn slots(&self) -> impl Iterator<Item = Option<&ItemStack>> {
+        self.slots.iter().map(Option::as_ref)
+    }
+
+    /// Total amount of an item across every slot, of any quality
+    pub fn count(&self, id: &ItemId) -> u32 {
+        self.slots
+            .iter()
+            .flatten()
+            .filter(|stack| stack.id == *id)
+            .map(|stack| stack.amount)
+            .sum()
+    }
+
+    /// Whether at least one of the item would fit, at the given quality
+    pub fn can_fit(&self, id: &ItemId, quality: ItemQuality) -> bool {
+        self.slots.iter().any(|slot| match slot {
+            Some(stack) => stack.id == *id && stack.quality == quality && !stack.is_full(),
+            None => true,
+        })
+    }
+
+    /// Adds some of an item, topping up existing stacks before starting new ones. Returns the
+    /// amount that didn't fit
+    pub fn add(&mut self, item: &ItemData, amount: u32) -> u32 {
+        self.add_stack(ItemStack::new(item, amount))
+    }
+
+    /// Same as [Inventory::add], for a stack that's already been taken out of somewhere
+    pub fn add_stack(&mut self, mut incoming: ItemStack) -> u32 {
+        for stack in self.slots.iter_mut().flatten() {
+            if incoming.amount == 0 {
+                return 0;
+            }
+            if !stack.stacks_with(&incoming) {
+                continue;
+            }
+            let moved = stack.space().min(incoming.amount);
+            stack.amount += moved;
+            incoming.amount -= moved;
+        }
+        for slot in self.slots.iter_mut() {
+            if incoming.amount == 0 {
+                return 0;
+            }
+            if slot.is_some() {
+                continue;
+            }
+            let moved = incoming.max_stack.min(incoming.amount);
+            incoming.amount -= moved;
+            *slot = Some(ItemStack {
+                amount: moved,
+                ..incoming.clone()
+            });
+        }
+        incoming.amount
+    }
+
+    /// Removes some of an item regardless of quality, taking from the last stacks first. Returns
+    /// the amount that couldn't be removed because there wasn't enough
+    pub fn remove(&mut self, id: &ItemId, mut amount: u32) -> u32 {
+        for slot in self.slots.iter_mut().rev() {
+            if amount == 0 {
+                break;
+            }
+            let Some(stack) = slot else {
+                continue;
+            };
+            if stack.id != *id {
+                continue;
+            }
+            let taken = stack.amount.min(amount);
+            stack.amount -= taken;
+            amount -= taken;
+            if stack.amount == 0 {
+                *slot = None;
+            }
+        }
+        amount
+    }
+
+    /// Takes up to `amount` out of a single slot
+    pub fn take(&mut self, index: usize, amount: u32) -> Option<ItemStack> {
+        let slot = self.slots.get_mut(index)?;
+        let stack = slot.as_mut()?;
+        let taken = stack.amount.min(amount);
+        if taken == 0 {
+            return None;
+        }
+        stack.amount -= taken;
+        let out = ItemStack {
+            amount: taken,
+            ..stack.clone()
+        };
+        if stack.amount == 0 {
+            *slot = None;
+        }
+        Some(out)
+    }
+
+    /// Puts a stack back into a specific slot, merging with a matching stack. Returns whatever
+    /// didn't fit, which is the whole stack if the slot holds something else
+    pub fn put(&mut self, index: usize, mut incoming: ItemStack) -> Option<ItemStack> {
+        let Some(slot) = self.slots.get_mut(index) else {
+            return Some(incoming);
+        };
+        match slot {
+            None => {
+                let moved = incoming.max_stack.min(incoming.amount);
+                incoming.amount -= moved;
+                *slot = Some(ItemStack {
+                    amount: moved,
+                    ..incoming.clone()
+                });
+            }
+            Some(stack) if stack.stacks_with(&incoming) => {
+                let moved = stack.space().min(incoming.amount);
+                stack.amount += moved;
+                incoming.amount -= moved;
+            }
+            Some(_) => (),
+        }
+        (incoming.amount > 0).then_some(incoming)
+    }
+
+    pub fn swap(&mut self, a: usize, b: usize) {
+        if a < self.slots.len() && b < self.slots.len() {
+            self.slots.swap(a, b);
+        }
+    }
+
+    /// Moves up to `amount` from one of our slots into another inventory. Anything that doesn't fit
+    /// stays in the slot, and the amount left behind is returned
+    pub fn transfer(&mut self, index: usize, other: &mut Inventory, amount: u32) -> u32 {
+        let Some(mut stack) = self.take(index, amount) else {
+            return 0;
+        };
+        let leftover = other.add_stack(stack.clone());
+        if leftover > 0 {
+            // the slot was just taken from, so the rest always fits back in
+            stack.amount = leftover;
+            self.put(index, stack);
+        }
+        leftover
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: &str, max_stack: u32) -> ItemData {
+        ItemData {
+            id: ItemId(id.into()),
+            max_stack,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn adding_tops_up_existing_stacks_first() {
+        let corn = item("corn", 10);
+        let mut inventory = Inventory::new(3);
+        assert_eq!(inventory.add(&corn, 4), 0);
+        assert_eq!(inventory.add(&corn, 8), 0);
+        assert_eq!(inventory.slot(0).map(|s| s.amount), Some(10));
+        assert_eq!(inventory.slot(1).map(|s| s.amount), Some(2));
+        assert_eq!(inventory.count(&corn.id), 12);
+    }
+
+    #[test]
+    fn overflow_is_returned_as_leftover() {
+        let corn = item("corn", 5);
+        let beets = item("beets", 5);
+        let mut inventory = Inventory::new(2);
+        inventory.add(&beets, 1);
+        assert_eq!(inventory.add(&corn, 8), 3);
+        assert!(!inventory.can_fit(&corn.id, ItemQuality::Normal));
+        assert!(inventory.can_fit(&beets.id, ItemQuality::Normal));
+
+        assert_eq!(inventory.remove(&corn.id, 7), 2);
+        assert_eq!(inventory.count(&corn.id), 0);
+        assert!(inventory.slot(1).is_none());
+    }
+
+    #[test]
+    fn partial_transfer_leaves_the_rest_behind() {
+        let corn = item("corn", 5);
+        let mut from = Inventory::new(1);
+        let mut to = Inventory::new(1);
+        from.add(&corn, 5);
+        to.add(&corn, 3);
+
+        assert_eq!(from.transfer(0, &mut to, 4), 2);
+        assert_eq!(to.count(&corn.id), 5);
+        assert_eq!(from.count(&corn.id), 3);
+        assert_eq!(from.transfer(0, &mut to, 1), 1);
+        assert_eq!(from.count(&corn.id), 3);
+    }
+
+    #[test]
+    fn different_qualities_use_separate_stacks() {
+        let corn = item("corn", 10);
+        let mut inventory = Inventory::new(2);
+        inventory.add(&corn, 3);
+        let gold = ItemStack::new(&corn, 2).with_quality(ItemQuality::Gold);
+        assert_eq!(inventory.add_stack(gold), 0);
+        assert_eq!(inventory.slot(0).map(|s| s.amount), Some(3));
+        assert_eq!(
+            inventory.slot(1).map(|s| s.quality),
+            Some(ItemQuality::Gold)
+        );
+        assert!(!inventory.can_fit(&corn.id, ItemQuality::Silver));
+        assert_eq!(inventory.count(&corn.id), 5);
+    }
+}
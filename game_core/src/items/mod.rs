@@ -1,35 +1,248 @@
-use bevy::prelude::*;
+use avian3d::prelude::{
+    ColliderConstructor, ColliderConstructorHierarchy, CollisionLayers, LayerMask, RigidBody,
+};
+use bevy::{asset::LoadState, prelude::*};
 use bevy_common_assets::ron::RonAssetPlugin;
 
 use serde::{Deserialize, Serialize};
 
-use crate::data::game_asset_path::GameAssetPath;
+use crate::{
+    collision::GameLayers, data::game_asset_path::GameAssetPath, interaction::Interactable,
+};
+use registry::ItemRegistry;
 
 pub mod drops;
+pub mod inventory;
+pub mod pickup;
+pub mod registry;
 pub struct ItemsPlugin;
 
 impl Plugin for ItemsPlugin {
     fn build(&self, app: &mut App) {
         // app.add_plugins()
         app.add_plugins(RonAssetPlugin::<ItemData>::new(&["item.ron"]));
+        app.init_resource::<ItemRegistry>();
+        app.add_systems(Startup, registry::scan_items);
+        app.add_systems(
+            Update,
+            (registry::index_items, registry::validate_item_references).chain(),
+        );
+        app.add_systems(
+            Update,
+            (
+                initialize_items,
+                (pickup::attract_items, pickup::collect_items).chain(),
+            ),
+        );
     }
 }
 
+/// Used for items that don't specify their own model
+const FALLBACK_ITEM_MODEL: &str = "::crate-color.glb";
+
 #[derive(Asset, Serialize, Deserialize, Reflect, Hash, Clone, PartialEq, Debug)]
 pub struct ItemData {
     pub id: ItemId,
+    /// Name shown to the player, falls back to the id when empty
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub category: ItemCategory,
+    /// Free-form labels for systems that care about groups of items, e.g. `"vegetable"`
+    #[serde(default)]
+    pub tags: Vec<String>,
     pub icon: String,
     pub model: Option<String>,
+    /// How many of this item fit in a single inventory slot
+    #[serde(default = "default_max_stack")]
+    pub max_stack: u32,
+    /// Price paid when selling a single item of normal quality
+    #[serde(default)]
+    pub sell_price: u32,
+    /// Whether this item comes in different [ItemQuality] levels
+    #[serde(default)]
+    pub has_quality: bool,
+    /// Set for items that do something when used from the hotbar, see [crate::tools]
+    #[serde(default)]
+    pub tool: Option<ToolKind>,
+    /// Id of the [crate::crops::data::CropDefinition] this item plants on tilled soil, for seeds
+    #[serde(default)]
+    pub crop: Option<String>,
+    /// Energy restored by eating this item. Items without it can't be eaten
+    #[serde(default)]
+    pub energy: Option<u32>,
+}
+
+fn default_max_stack() -> u32 {
+    99
+}
+
+impl Default for ItemData {
+    fn default() -> Self {
+        Self {
+            id: ItemId::default(),
+            name: String::new(),
+            description: String::new(),
+            category: ItemCategory::default(),
+            tags: Vec::new(),
+            icon: String::new(),
+            model: None,
+            max_stack: default_max_stack(),
+            sell_price: 0,
+            has_quality: false,
+            tool: None,
+            crop: None,
+            energy: None,
+        }
+    }
+}
+
+impl ItemData {
+    pub fn display_name(&self) -> &str {
+        if self.name.is_empty() {
+            &self.id.0
+        } else {
+            &self.name
+        }
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+
+    /// What a single item sells for at the given quality
+    pub fn sell_price_for(&self, quality: ItemQuality) -> u32 {
+        if !self.has_quality {
+            return self.sell_price;
+        }
+        (self.sell_price as f32 * quality.price_multiplier()).round() as u32
+    }
+}
+
+#[derive(Serialize, Deserialize, Reflect, Hash, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum ItemCategory {
+    Seed,
+    Crop,
+    Tool,
+    Food,
+    #[default]
+    Material,
+}
+
+/// What an item does when it's used from the hotbar
+#[derive(Serialize, Deserialize, Reflect, Hash, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ToolKind {
+    /// Tills soil so it can be planted in
+    Hoe,
+    /// Waters tilled soil
+    WateringCan,
+    /// Harvests and clears away crops
+    Scythe,
+    /// Chops wooden things apart
+    Axe,
+}
+
+impl ToolKind {
+    /// Energy used up by a single swing
+    pub fn energy_cost(&self) -> f32 {
+        match self {
+            ToolKind::Hoe => 2.0,
+            ToolKind::WateringCan => 2.0,
+            ToolKind::Scythe => 1.0,
+            ToolKind::Axe => 4.0,
+        }
+    }
+}
+
+/// How good a particular item is. Only matters for items with [ItemData::has_quality], and can be
+/// put on a world item entity to give it a quality other than normal
+#[derive(
+    Component,
+    Serialize,
+    Deserialize,
+    Reflect,
+    Hash,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Default,
+    Debug,
+)]
+pub enum ItemQuality {
+    #[default]
+    Normal,
+    Silver,
+    Gold,
+}
+
+impl ItemQuality {
+    pub fn price_multiplier(self) -> f32 {
+        match self {
+            ItemQuality::Normal => 1.0,
+            ItemQuality::Silver => 1.25,
+            ItemQuality::Gold => 1.5,
+        }
+    }
 }
 
 #[derive(Component, Clone, PartialEq)]
 pub struct Item(pub Handle<ItemData>);
 
-#[derive(Serialize, Deserialize, Reflect, Hash, Clone, PartialEq, Eq, Debug)]
+#[derive(Serialize, Deserialize, Reflect, Hash, Clone, PartialEq, Eq, Default, Debug)]
 pub struct ItemId(pub String);
 
-impl Item {
-    pub fn from_id(id: ItemId, assets: &Res<AssetServer>) -> Self {
-        Self(assets.load(GameAssetPath::new_data(format!("::items/{}", id.0))))
+/// Spawns a physical item in the world. The model and physics are attached once the item data has
+/// finished loading, see [initialize_items]
+pub fn spawn_item(cmd: &mut Commands, item: Item, position: Vec3) -> Entity {
+    cmd.spawn((
+        Name::new("Item"),
+        item,
+        SpatialBundle {
+            transform: Transform::from_translation(position).with_scale(Vec3::ONE * 0.5),
+            ..default()
+        },
+    ))
+    .id()
+}
+
+fn initialize_items(
+    query: Query<(&Item, Entity), Without<Handle<Scene>>>, mut cmd: Commands,
+    assets: Res<AssetServer>, items: Res<Assets<ItemData>>,
+) {
+    for (item, entity) in query.iter() {
+        match assets.load_state(item.0.id()) {
+            LoadState::Loaded => (),
+            LoadState::Failed(err) => {
+                error!("Failed to load item data for {}: {}", entity, err);
+                cmd.entity(entity).despawn_recursive();
+                continue;
+            }
+            _ => continue,
+        }
+        let Some(data) = items.get(item.0.id()) else {
+            continue;
+        };
+        let model = data
+            .model
+            .clone()
+            .unwrap_or_else(|| FALLBACK_ITEM_MODEL.into());
+        cmd.entity(entity)
+            .insert((
+                Name::new(data.display_name().to_string()),
+                assets.load::<Scene>(GameAssetPath::new_model(model).gltf_scene()),
+                RigidBody::Dynamic,
+                Interactable,
+                CollisionLayers::new(
+                    [GameLayers::Default, GameLayers::Interactable],
+                    LayerMask::ALL,
+                ),
+                ColliderConstructorHierarchy::new(ColliderConstructor::ConvexHullFromMesh),
+            ))
+            .observe(pickup::pick_up_item);
     }
 }
@@ -0,0 +1,113 @@
+use avian3d::prelude::LinearVelocity;
+use bevy::prelude::*;
+
+use crate::{
+    common_events::{ItemPickedUp, PlayerInteract},
+    player::PlayerMarker,
+};
+
+use super::{
+    inventory::{Inventory, ItemStack},
+    Item, ItemData, ItemQuality,
+};
+
+/// Items within this distance of the player drift towards them
+pub const MAGNET_RADIUS: f32 = 4.0;
+/// Items within this distance of the player get picked up
+pub const PICKUP_RADIUS: f32 = 1.5;
+/// Top speed of an item being pulled in
+const MAGNET_SPEED: f32 = 8.0;
+
+/// Pulls nearby items towards the player, as long as there's room for them
+pub fn attract_items(
+    player_query: Query<(&GlobalTransform, &Inventory), With<PlayerMarker>>,
+    mut query: Query<(
+        &Item,
+        Option<&ItemQuality>,
+        &GlobalTransform,
+        &mut LinearVelocity,
+    )>,
+    items: Res<Assets<ItemData>>,
+) {
+    let Ok((player, inventory)) = player_query.get_single() else {
+        return;
+    };
+    for (item, quality, trans, mut velocity) in query.iter_mut() {
+        let offset = player.translation() - trans.translation();
+        let distance = offset.length();
+        if distance > MAGNET_RADIUS {
+            continue;
+        }
+        let Some(data) = items.get(item.0.id()) else {
+            continue;
+        };
+        if !inventory.can_fit(&data.id, quality.copied().unwrap_or_default()) {
+            continue;
+        }
+        // pull harder the closer the item gets
+        let strength = 1.0 - distance / MAGNET_RADIUS;
+        velocity.0 = velocity
+            .0
+            .lerp(offset.normalize_or_zero() * MAGNET_SPEED, strength);
+    }
+}
+
+/// Picks up any items that made it close enough to the player
+pub fn collect_items(
+    mut player_query: Query<(&GlobalTransform, &mut Inventory), With<PlayerMarker>>,
+    query: Query<(&Item, Option<&ItemQuality>, &GlobalTransform, Entity)>,
+    items: Res<Assets<ItemData>>, mut cmd: Commands,
+) {
+    let Ok((player, mut inventory)) = player_query.get_single_mut() else {
+        return;
+    };
+    for (item, quality, trans, entity) in query.iter() {
+        if player.translation().distance(trans.translation()) > PICKUP_RADIUS {
+            continue;
+        }
+        let Some(data) = items.get(item.0.id()) else {
+            continue;
+        };
+        let stack = ItemStack::new(data, 1).with_quality(quality.copied().unwrap_or_default());
+        try_pick_up(&mut cmd, &mut inventory, stack, entity);
+    }
+}
+
+/// Lets the player grab an item by interacting with it directly
+pub fn pick_up_item(
+    trigger: Trigger<PlayerInteract>, mut player_query: Query<&mut Inventory, With<PlayerMarker>>,
+    query: Query<(&Item, Option<&ItemQuality>)>, items: Res<Assets<ItemData>>, mut cmd: Commands,
+) {
+    let entity = trigger.entity();
+    let Ok(mut inventory) = player_query.get_single_mut() else {
+        return;
+    };
+    let Ok((item, quality)) = query.get(entity) else {
+        return;
+    };
+    let Some(data) = items.get(item.0.id()) else {
+        return;
+    };
+    let stack = ItemStack::new(data, 1).with_quality(quality.copied().unwrap_or_default());
+    if !try_pick_up(&mut cmd, &mut inventory, stack, entity) {
+        info!("No room in the inventory for {}", data.display_name());
+    }
+}
+
+/// Moves a world item into the inventory. If it doesn't fit the item is left where it is
+fn try_pick_up(
+    cmd: &mut Commands, inventory: &mut Inventory, stack: ItemStack, entity: Entity,
+) -> bool {
+    if !inventory.can_fit(&stack.id, stack.quality) {
+        return false;
+    }
+    let (item, amount, quality) = (stack.id.clone(), stack.amount, stack.quality);
+    inventory.add_stack(stack);
+    cmd.entity(entity).despawn_recursive();
+    cmd.trigger(ItemPickedUp {
+        item,
+        amount,
+        quality,
+    });
+    true
+}
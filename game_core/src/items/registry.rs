@@ -0,0 +1,133 @@
+use bevy::{asset::LoadedFolder, ecs::system::SystemParam, prelude::*, utils::HashMap};
+
+use crate::{
+    crops::{data::CropDefinition, registry::CropRegistry},
+    data::{game_asset_path::GameAssetPath, named_asset_id::FolderRegistry},
+};
+
+use super::{Item, ItemData, ItemId};
+
+/// Every item definition found in `::items`, indexed by [ItemData::id]
+#[derive(Resource, Default)]
+pub struct ItemRegistry {
+    items: FolderRegistry<ItemData>,
+    /// Loaded up front so UI can show icons without going through the asset server every frame
+    icons: HashMap<String, Handle<Image>>,
+}
+
+impl ItemRegistry {
+    pub fn get(&self, id: &ItemId) -> Option<Handle<ItemData>> {
+        self.items.get(id.0.clone())
+    }
+
+    /// An [Item] component for the given id, if it exists
+    pub fn item(&self, id: &ItemId) -> Option<Item> {
+        self.get(id).map(Item)
+    }
+
+    /// The texture to show for an item in the UI
+    pub fn icon(&self, id: &ItemId) -> Option<Handle<Image>> {
+        self.icons.get(&id.0).cloned()
+    }
+
+    pub fn contains(&self, id: &ItemId) -> bool {
+        self.items.contains(id.0.clone())
+    }
+
+    /// All known item ids, in no particular order
+    pub fn ids(&self) -> impl Iterator<Item = &String> {
+        self.items.names()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Handle<ItemData>)> {
+        self.items.iter()
+    }
+
+    /// Whether the items folder has finished loading and been indexed
+    pub fn is_loaded(&self) -> bool {
+        self.items.is_loaded()
+    }
+}
+
+/// Looks up loaded [ItemData] by id
+#[derive(SystemParam)]
+pub struct ItemDefinitions<'w> {
+    registry: Res<'w, ItemRegistry>,
+    items: Res<'w, Assets<ItemData>>,
+}
+
+impl ItemDefinitions<'_> {
+    pub fn get(&self, id: &ItemId) -> Option<&ItemData> {
+        let handle = self.registry.get(id)?;
+        self.items.get(handle.id())
+    }
+
+    pub fn is_loaded(&self) -> bool {
+        self.registry.is_loaded()
+    }
+}
+
+pub(super) fn scan_items(mut registry: ResMut<ItemRegistry>, assets: Res<AssetServer>) {
+    registry
+        .items
+        .scan(&assets, GameAssetPath::new_data("::items"));
+}
+
+pub(super) fn index_items(
+    mut events: EventReader<AssetEvent<LoadedFolder>>, mut registry: ResMut<ItemRegistry>,
+    folders: Res<Assets<LoadedFolder>>, definitions: Res<Assets<ItemData>>,
+    assets: Res<AssetServer>,
+) {
+    let Some(folder) = registry.items.loaded_folder(&mut events, &folders) else {
+        return;
+    };
+    registry
+        .items
+        .index(folder, &definitions, "item", |data| data.id.0.clone());
+    let icons = registry
+        .items
+        .iter()
+        .filter_map(|(id, handle)| {
+            let data = definitions.get(handle.id())?;
+            let icon = assets.load(GameAssetPath::new_texture(data.icon.clone()));
+            Some((id.clone(), icon))
+        })
+        .collect();
+    registry.icons = icons;
+}
+
+/// Reports item ids referenced by crop drops that don't exist, and crops planted by seeds that
+/// don't exist, once both registries are ready. There are no recipes yet, they should be checked
+/// here too once they exist
+pub(super) fn validate_item_references(
+    items: Res<ItemRegistry>, crops: Res<CropRegistry>, definitions: Res<Assets<CropDefinition>>,
+    item_definitions: Res<Assets<ItemData>>,
+) {
+    if !(items.is_changed() || crops.is_changed()) || !items.is_loaded() || !crops.is_loaded() {
+        return;
+    }
+    for (crop, handle) in crops.iter() {
+        let Some(def) = definitions.get(handle.id()) else {
+            continue;
+        };
+        for (index, drop) in def.drops().enumerate() {
+            if !items.contains(&drop.item) {
+                error!(
+                    "Crop \"{}\" drops unknown item \"{}\" (drop #{})",
+                    crop, drop.item.0, index
+                );
+            }
+        }
+    }
+    for (id, handle) in items.iter() {
+        let Some(crop) = item_definitions
+            .get(handle.id())
+            .and_then(|data| data.crop.as_ref())
+        else {
+            continue;
+        };
+        if !crops.contains(crop) {
+            error!("Item \"{}\" plants unknown crop \"{}\"", id, crop);
+        }
+    }
+}
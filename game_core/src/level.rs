@@ -7,7 +7,7 @@ use bevy::{
     },
 };
 
-use crate::{collision::GameLayers, data::game_asset_path::GameAssetPath};
+use crate::{collision::GameLayers, data::game_asset_path::GameAssetPath, lighting::Sun};
 
 pub struct LevelPlugin;
 
@@ -51,13 +51,17 @@ fn create_level(
             ..default()
         },
     ));
-    cmd.spawn(DirectionalLightBundle {
-        directional_light: DirectionalLight {
-            illuminance: 1000.,
-            shadows_enabled: true,
+    cmd.spawn((
+        Name::from("Sun"),
+        Sun,
+        DirectionalLightBundle {
+            directional_light: DirectionalLight {
+                illuminance: 1000.,
+                shadows_enabled: true,
+                ..default()
+            },
+            transform: Transform::from_xyz(0.5, 1., 0.5).looking_at(Vec3::ZERO, Vec3::Y),
             ..default()
         },
-        transform: Transform::from_xyz(0.5, 1., 0.5).looking_at(Vec3::ZERO, Vec3::Y),
-        ..default()
-    });
+    ));
 }
@@ -9,6 +9,7 @@ use bevy::{
     prelude::*,
     window::PresentMode,
 };
+use bevy_alt_ui_navigation_lite::DefaultNavigationPlugins;
 use bevy_hanabi::HanabiPlugin;
 use bevy_inspector_egui::quick::WorldInspectorPlugin;
 use bevy_rand::{
@@ -22,15 +23,23 @@ use bevy_tweening::TweeningPlugin;
 
 use common_events::CommonEventsPlugin;
 use crops::CropsPlugin;
-use days::DaysPlugin;
+use days::{DaysPlugin, GameClock, PauseReason};
+use energy::EnergyPlugin;
+use farm::FarmPlugin;
 use hud::HudPlugin;
 use interaction::InteractionPlugin;
+use inventory_screen::InventoryScreenPlugin;
 use items::ItemsPlugin;
 use level::LevelPlugin;
+use lighting::LightingPlugin;
 use mouse::MousePlugin;
 use player::PlayerPlugin;
 use settings::GameSettingsPlugin;
+use shipping::ShippingPlugin;
+use sleep::SleepPlugin;
+use tools::ToolsPlugin;
 use vfx::VfxPlugin;
+use weather::WeatherPlugin;
 
 pub type Random = GlobalEntropy<WyRand>;
 
@@ -40,14 +49,22 @@ pub mod constants;
 pub mod crops;
 pub mod data;
 pub mod days;
+pub mod energy;
+pub mod farm;
 pub mod hud;
 pub mod interaction;
+pub mod inventory_screen;
 pub mod items;
 pub mod level;
+pub mod lighting;
 pub mod mouse;
 pub mod player;
 pub mod settings;
+pub mod shipping;
+pub mod sleep;
+pub mod tools;
 pub mod vfx;
+pub mod weather;
 pub mod dev_assertions;
 
 pub struct GamePlugins;
@@ -105,6 +122,7 @@ impl Plugin for GamePlugins {
         app.add_plugins(ScreenEntityDiagnosticsPlugin);
         app.add_plugins(EntropyPlugin::<WyRand>::default());
         app.add_plugins(HanabiPlugin);
+        app.add_plugins(DefaultNavigationPlugins);
         app.add_plugins(
             WorldInspectorPlugin::default().run_if(input_toggle_active(false, KeyCode::F1)),
         );
@@ -118,12 +136,24 @@ impl Plugin for GamePlugins {
             InteractionPlugin,
             CommonEventsPlugin,
             HudPlugin,
+            InventoryScreenPlugin,
+            VfxPlugin,
+        ));
+        // Gameplay
+        app.add_plugins((
             ItemsPlugin,
+            ToolsPlugin,
+            FarmPlugin,
             CropsPlugin,
             DaysPlugin,
-            VfxPlugin,
+            WeatherPlugin,
+            LightingPlugin,
+            SleepPlugin,
+            EnergyPlugin,
+            ShippingPlugin,
         ));
         app.add_systems(PostStartup, add_fallback_camera);
+        app.add_systems(Update, pause_for_inspector);
         if cfg!(debug_assertions) {
             // only do this in development (when debug assertions are available)
             app.add_systems(Update, exit_on_f8);
@@ -138,6 +168,16 @@ fn add_fallback_camera(mut commands: Commands, query: Query<&Camera>) {
     }
 }
 
+/// Mirrors the F1 toggle on the world inspector, so game time doesn't run while poking at it
+fn pause_for_inspector(
+    keys: Res<ButtonInput<KeyCode>>, mut game_clock: ResMut<GameClock>, mut open: Local<bool>,
+) {
+    if keys.just_pressed(KeyCode::F1) {
+        *open = !*open;
+        game_clock.set_paused(PauseReason::Inspector, *open);
+    }
+}
+
 /// Entirely because I still have Godot muscle memory, I press F8 anyway when I
 /// wanna close the app. So this will make that actually work
 fn exit_on_f8(keys: Res<ButtonInput<KeyCode>>, mut exit: EventWriter<AppExit>) {
@@ -0,0 +1,162 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+use crate::{days::TimeOfDay, weather::Forecast};
+
+pub struct LightingPlugin;
+
+impl Plugin for LightingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DayLighting>();
+        app.add_systems(Update, apply_day_lighting);
+    }
+}
+
+/// Marks the directional light that gets moved around by the day/night cycle
+#[derive(Component)]
+pub struct Sun;
+
+/// Lighting at a particular hour of the day. Values in between keyframes are interpolated
+#[derive(Debug, Clone)]
+pub struct LightingKeyframe {
+    pub hour: f32,
+    /// Angle of the sun above the horizon, in degrees
+    pub sun_elevation: f32,
+    pub illuminance: f32,
+    pub ambient_color: Color,
+    pub ambient_brightness: f32,
+    pub clear_color: Color,
+}
+
+/// Keyframes for the day/night cycle, which must be sorted by hour
+#[derive(Resource, Debug, Clone)]
+pub struct DayLighting {
+    pub keyframes: Vec<LightingKeyframe>,
+}
+
+impl Default for DayLighting {
+    fn default() -> Self {
+        let night = LightingKeyframe {
+            hour: 22.0,
+            // moonlight, just coming from the same light
+            sun_elevation: 30.0,
+            illuminance: 80.0,
+            ambient_color: Color::srgb(0.3, 0.3, 0.5),
+            ambient_brightness: 40.0,
+            clear_color: Color::srgb(0.02, 0.02, 0.08),
+        };
+        Self {
+            keyframes: vec![
+                LightingKeyframe {
+                    hour: 6.0,
+                    sun_elevation: 5.0,
+                    illuminance: 600.0,
+                    ambient_color: Color::srgb(0.9, 0.7, 0.7),
+                    ambient_brightness: 80.0,
+                    clear_color: Color::srgb(0.55, 0.45, 0.5),
+                },
+                LightingKeyframe {
+                    hour: 9.0,
+                    sun_elevation: 35.0,
+                    illuminance: 2500.0,
+                    ambient_color: Color::WHITE,
+                    ambient_brightness: 200.0,
+                    clear_color: Color::srgb(0.45, 0.65, 0.9),
+                },
+                LightingKeyframe {
+                    hour: 13.0,
+                    sun_elevation: 70.0,
+                    illuminance: 4000.0,
+                    ambient_color: Color::WHITE,
+                    ambient_brightness: 300.0,
+                    clear_color: Color::srgb(0.5, 0.7, 0.95),
+                },
+                LightingKeyframe {
+                    hour: 18.0,
+                    sun_elevation: 20.0,
+                    illuminance: 2000.0,
+                    ambient_color: Color::srgb(1.0, 0.8, 0.6),
+                    ambient_brightness: 150.0,
+                    clear_color: Color::srgb(0.85, 0.55, 0.35),
+                },
+                LightingKeyframe {
+                    hour: 20.0,
+                    sun_elevation: 2.0,
+                    illuminance: 300.0,
+                    ambient_color: Color::srgb(0.5, 0.4, 0.6),
+                    ambient_brightness: 60.0,
+                    clear_color: Color::srgb(0.2, 0.15, 0.3),
+                },
+                night.clone(),
+                LightingKeyframe {
+                    hour: 26.0,
+                    ..night
+                },
+            ],
+        }
+    }
+}
+
+impl DayLighting {
+    /// Interpolates the lighting for the given hour, holding the first and last keyframes past
+    /// either end
+    pub fn sample(&self, hour: f32) -> Option<LightingKeyframe> {
+        let first = self.keyframes.first()?;
+        if hour <= first.hour {
+            return Some(first.clone());
+        }
+        for pair in self.keyframes.windows(2) {
+            let (from, to) = (&pair[0], &pair[1]);
+            if hour > to.hour {
+                continue;
+            }
+            let t = (hour - from.hour) / (to.hour - from.hour).max(f32::EPSILON);
+            return Some(LightingKeyframe {
+                hour,
+                sun_elevation: from.sun_elevation.lerp(to.sun_elevation, t),
+                illuminance: from.illuminance.lerp(to.illuminance, t),
+                ambient_color: lerp_color(from.ambient_color, to.ambient_color, t),
+                ambient_brightness: from.ambient_brightness.lerp(to.ambient_brightness, t),
+                clear_color: lerp_color(from.clear_color, to.clear_color, t),
+            });
+        }
+        self.keyframes.last().cloned()
+    }
+}
+
+fn lerp_color(from: Color, to: Color, t: f32) -> Color {
+    let mixed = from.to_linear().to_vec4().lerp(to.to_linear().to_vec4(), t);
+    Color::linear_rgba(mixed.x, mixed.y, mixed.z, mixed.w)
+}
+
+fn apply_day_lighting(
+    clock: Res<TimeOfDay>, lighting: Res<DayLighting>, forecast: Option<Res<Forecast>>,
+    mut sun_query: Query<(&mut DirectionalLight, &mut Transform), With<Sun>>,
+    mut ambient: ResMut<AmbientLight>, mut clear_color: ResMut<ClearColor>,
+) {
+    let Some(frame) = lighting.sample(clock.hours()) else {
+        return;
+    };
+    // overcast days are darker and greyer across the board
+    let overcast = forecast.map_or(1.0, |forecast| forecast.today.light_factor());
+    // sweep from east to west over the course of the day
+    let azimuth = PI * (0.5 - clock.day_progress());
+    for (mut light, mut trans) in sun_query.iter_mut() {
+        light.illuminance = frame.illuminance * overcast;
+        trans.rotation = Quat::from_euler(
+            EulerRot::YXZ,
+            azimuth,
+            -frame.sun_elevation.to_radians(),
+            0.,
+        );
+    }
+    ambient.color = frame.ambient_color;
+    ambient.brightness = frame.ambient_brightness * overcast.sqrt();
+    let grey = frame.clear_color.luminance() * overcast.sqrt();
+    clear_color.0 = lerp_color(
+        Color::linear_rgb(grey, grey, grey),
+        frame.clear_color,
+        overcast,
+    );
+}
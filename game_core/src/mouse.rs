@@ -18,7 +18,7 @@ impl Plugin for MousePlugin {
 }
 
 #[derive(Event, Clone, Debug, Hash, PartialEq, Eq)]
-pub struct MouseStateRequest(MouseState);
+pub struct MouseStateRequest(pub MouseState);
 
 #[derive(States, Debug, Hash, PartialEq, Eq, Clone, Default)]
 pub enum MouseState {
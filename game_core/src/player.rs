@@ -10,7 +10,7 @@ use bevy_tnua_avian3d::{TnuaAvian3dPlugin, TnuaAvian3dSensorShape};
 use leafwing_input_manager::{
     plugin::InputManagerPlugin,
     prelude::{
-        ActionState, GamepadStick, InputMap, KeyboardVirtualDPad, MouseMove,
+        ActionState, GamepadStick, InputMap, KeyboardVirtualDPad, MouseMove, MouseScrollDirection,
         WithDualAxisProcessingPipelineExt,
     },
     Actionlike, InputControlKind, InputManagerBundle,
@@ -19,7 +19,9 @@ use leafwing_input_manager::{
 use crate::{
     collision::GameLayers,
     common_events::{PlayerInteract, PlayerInteractionChanged},
+    energy::{Energy, PLAYER_MAX_ENERGY, SPRINT_ENERGY_PER_SECOND},
     interaction::Interactable,
+    items::inventory::{HotbarSelection, Inventory, HOTBAR_SLOTS, PLAYER_INVENTORY_SLOTS},
     mouse::MouseState,
 };
 
@@ -44,6 +46,7 @@ impl Plugin for PlayerPlugin {
                 player_move,
                 emit_interaction_events,
                 dispatch_interactions,
+                select_hotbar_slot,
             )
                 .in_set(SchedulePlayerMouseLocked),
         );
@@ -54,7 +57,7 @@ impl Plugin for PlayerPlugin {
 struct SchedulePlayerMouseLocked;
 
 fn create_player(mut cmd: Commands, mut meshes: ResMut<Assets<Mesh>>) {
-    let input = InputMap::<InputActions>::default()
+    let mut input = InputMap::<InputActions>::default()
         .with_dual_axis(InputActions::Move, KeyboardVirtualDPad::WASD)
         .with_dual_axis(InputActions::Move, KeyboardVirtualDPad::ARROW_KEYS)
         .with_dual_axis(
@@ -67,13 +70,37 @@ fn create_player(mut cmd: Commands, mut meshes: ResMut<Assets<Mesh>>) {
             GamepadStick::RIGHT.with_circle_deadzone(0.1).inverted(),
         )
         .with(InputActions::Jump, KeyCode::Space)
+        .with(InputActions::Sprint, KeyCode::ShiftLeft)
         .with(InputActions::Primary, MouseButton::Left)
         .with(InputActions::Secondary, MouseButton::Right)
         .with(InputActions::Interact, KeyCode::KeyE)
+        .with(InputActions::Inventory, KeyCode::Tab)
+        .with(InputActions::Cancel, KeyCode::Escape)
+        .with(InputActions::HotbarNext, MouseScrollDirection::DOWN)
+        .with(InputActions::HotbarPrevious, MouseScrollDirection::UP)
         .with(InputActions::Jump, GamepadButtonType::East)
-        .with(InputActions::Primary, GamepadButtonType::RightTrigger)
-        .with(InputActions::Secondary, GamepadButtonType::LeftTrigger)
-        .with(InputActions::Interact, GamepadButtonType::South);
+        .with(InputActions::Sprint, GamepadButtonType::LeftThumb)
+        .with(InputActions::Primary, GamepadButtonType::RightTrigger2)
+        .with(InputActions::Secondary, GamepadButtonType::LeftTrigger2)
+        .with(InputActions::Interact, GamepadButtonType::South)
+        .with(InputActions::Inventory, GamepadButtonType::North)
+        .with(InputActions::Cancel, GamepadButtonType::East)
+        .with(InputActions::HotbarNext, GamepadButtonType::RightTrigger)
+        .with(InputActions::HotbarPrevious, GamepadButtonType::LeftTrigger);
+    let digits = [
+        KeyCode::Digit1,
+        KeyCode::Digit2,
+        KeyCode::Digit3,
+        KeyCode::Digit4,
+        KeyCode::Digit5,
+        KeyCode::Digit6,
+        KeyCode::Digit7,
+        KeyCode::Digit8,
+        KeyCode::Digit9,
+    ];
+    for (slot, key) in digits.into_iter().enumerate().take(HOTBAR_SLOTS) {
+        input.insert(InputActions::HotbarSlot(slot), key);
+    }
 
     cmd.spawn((
         Name::new("Player"),
@@ -83,6 +110,10 @@ fn create_player(mut cmd: Commands, mut meshes: ResMut<Assets<Mesh>>) {
         CollisionLayers::new(GameLayers::Player, LayerMask::ALL),
         TnuaControllerBundle::default(),
         LastInteractable::default(),
+        AimPoint::default(),
+        Inventory::new(PLAYER_INVENTORY_SLOTS),
+        HotbarSelection::default(),
+        Energy::new(PLAYER_MAX_ENERGY),
         TnuaAvian3dSensorShape(Collider::cylinder(0.49, 0.01)),
         // allow Y rotation for looking around
         LockedAxes::new().lock_rotation_x().lock_rotation_z(),
@@ -129,8 +160,9 @@ fn create_player(mut cmd: Commands, mut meshes: ResMut<Assets<Mesh>>) {
     });
 }
 
+/// Marks the player's root entity
 #[derive(Component)]
-struct PlayerMarker;
+pub struct PlayerMarker;
 
 #[derive(Component)]
 struct FpsCameraMarker;
@@ -138,8 +170,13 @@ struct FpsCameraMarker;
 #[derive(Component)]
 struct FpsCameraRoot;
 
+/// The interactable entity the player is currently looking at
+#[derive(Component, Default, Debug)]
+pub struct LastInteractable(pub Option<Entity>);
+
+/// Where the player's view hits the world, used by tools that act on the ground
 #[derive(Component, Default, Debug)]
-struct LastInteractable(Option<Entity>);
+pub struct AimPoint(pub Option<Vec3>);
 
 fn camera_look(
     mut cam_query: Query<&mut Transform, (With<FpsCameraRoot>, Without<PlayerMarker>)>,
@@ -172,14 +209,19 @@ fn camera_look(
     );
 }
 
+const WALK_SPEED: f32 = 10.0;
+const SPRINT_SPEED: f32 = 15.0;
+const EXHAUSTED_SPEED: f32 = 5.0;
+
 fn player_move(
     mut controller_query: Query<
-        (&mut TnuaController, &ActionState<InputActions>),
+        (&mut TnuaController, &ActionState<InputActions>, &mut Energy),
         (With<PlayerMarker>, Without<FpsCameraRoot>),
     >,
     cam_query: Query<&GlobalTransform, (With<FpsCameraRoot>, Without<PlayerMarker>)>,
+    time: Res<Time>,
 ) {
-    let Ok((mut controller, input)) = controller_query.get_single_mut() else {
+    let Ok((mut controller, input, mut energy)) = controller_query.get_single_mut() else {
         return;
     };
     let Ok(cam) = cam_query.get_single() else {
@@ -193,8 +235,19 @@ fn player_move(
 
     // clear Y motion to avoid issues when looking up/down
     let desired = (basis_neg_z * movement.y + basis_pos_x * movement.x) * Vec3::new(1., 0., 1.);
+    let moving = desired.length_squared() > 0.0;
+    let speed = if energy.is_exhausted() {
+        EXHAUSTED_SPEED
+    } else if moving
+        && input.pressed(&InputActions::Sprint)
+        && energy.spend(SPRINT_ENERGY_PER_SECOND * time.delta_seconds())
+    {
+        SPRINT_SPEED
+    } else {
+        WALK_SPEED
+    };
     controller.basis(TnuaBuiltinWalk {
-        desired_velocity: desired.normalize_or_zero() * 10.0,
+        desired_velocity: desired.normalize_or_zero() * speed,
         float_height: 1.5,
         ..default()
     });
@@ -209,11 +262,11 @@ fn player_move(
 }
 
 fn emit_interaction_events(
-    mut interact_query: Query<&mut LastInteractable, With<PlayerMarker>>,
+    mut interact_query: Query<(&mut LastInteractable, &mut AimPoint), With<PlayerMarker>>,
     root_query: Query<&GlobalTransform, With<FpsCameraRoot>>, spatial_query: SpatialQuery,
     parent_query: Query<&Parent>, interactable_query: Query<&Interactable>, mut commands: Commands,
 ) {
-    let Ok(mut interact) = interact_query.get_single_mut() else {
+    let Ok((mut interact, mut aim)) = interact_query.get_single_mut() else {
         warn!("No last interactable component on player!");
         return;
     };
@@ -221,6 +274,15 @@ fn emit_interaction_events(
         warn!("No global transform on FPS Camera Root");
         return;
     };
+    // the player is on its own layer, so this only ever hits the world
+    let ground = spatial_query.cast_ray(
+        root.translation(),
+        root.forward(),
+        50.0,
+        true,
+        SpatialQueryFilter::from_mask(GameLayers::Default),
+    );
+    aim.0 = ground.map(|hit| root.translation() + root.forward() * hit.time_of_impact);
     // try hit with raycast
     let ray_data = spatial_query.cast_ray(
         root.translation(),
@@ -290,15 +352,43 @@ fn dispatch_interactions(
     info!("Player dispatched interaction event");
 }
 
+fn select_hotbar_slot(
+    mut query: Query<(&mut HotbarSelection, &ActionState<InputActions>), With<PlayerMarker>>,
+) {
+    let Ok((mut selection, input)) = query.get_single_mut() else {
+        return;
+    };
+    let mut slot = selection.0;
+    if input.just_pressed(&InputActions::HotbarNext) {
+        slot = (slot + 1) % HOTBAR_SLOTS;
+    }
+    if input.just_pressed(&InputActions::HotbarPrevious) {
+        slot = (slot + HOTBAR_SLOTS - 1) % HOTBAR_SLOTS;
+    }
+    for index in 0..HOTBAR_SLOTS {
+        if input.just_pressed(&InputActions::HotbarSlot(index)) {
+            slot = index;
+        }
+    }
+    selection.set_if_neq(HotbarSelection(slot));
+}
+
 #[derive(Reflect, Clone, PartialEq, Eq, Hash, Debug)]
 pub enum InputActions {
     Move,
     Look,
     Jump,
+    /// Runs faster at the cost of energy
+    Sprint,
     Interact,
     Primary,
     Secondary,
     Cancel,
+    Inventory,
+    HotbarNext,
+    HotbarPrevious,
+    /// Jumps straight to a hotbar slot, counting from 0
+    HotbarSlot(usize),
 }
 
 impl Actionlike for InputActions {
@@ -308,9 +398,14 @@ impl Actionlike for InputActions {
             InputActions::Look => InputControlKind::DualAxis,
             InputActions::Cancel => InputControlKind::Button,
             InputActions::Jump => InputControlKind::Button,
+            InputActions::Sprint => InputControlKind::Button,
             InputActions::Primary => InputControlKind::Button,
             InputActions::Secondary => InputControlKind::Button,
             InputActions::Interact => InputControlKind::Button,
+            InputActions::Inventory => InputControlKind::Button,
+            InputActions::HotbarNext => InputControlKind::Button,
+            InputActions::HotbarPrevious => InputControlKind::Button,
+            InputActions::HotbarSlot(_) => InputControlKind::Button,
         }
     }
 }
@@ -0,0 +1,137 @@
+use bevy::prelude::*;
+
+use crate::{
+    common_events::{DayEarnings, NewDay, PlayerInteract, SoldItem},
+    data::game_asset_path::GameAssetPath,
+    interaction::spawn_interactable_prop,
+    items::{
+        inventory::{HotbarSelection, Inventory, ItemStack},
+        registry::ItemDefinitions,
+    },
+    player::PlayerMarker,
+};
+
+pub struct ShippingPlugin;
+
+impl Plugin for ShippingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Wallet>();
+        app.add_systems(Startup, create_shipping_bin);
+        app.observe(sell_shipped_items);
+    }
+}
+
+/// The player's money
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Wallet(pub u32);
+
+impl Wallet {
+    pub fn earn(&mut self, amount: u32) {
+        self.0 = self.0.saturating_add(amount);
+    }
+
+    /// Takes money out of the wallet, returns false without spending anything if there isn't
+    /// enough
+    pub fn spend(&mut self, amount: u32) -> bool {
+        let Some(left) = self.0.checked_sub(amount) else {
+            return false;
+        };
+        self.0 = left;
+        true
+    }
+}
+
+/// Items dropped in here during the day are sold overnight
+#[derive(Component, Debug, Default)]
+pub struct ShippingBin(pub Vec<ItemStack>);
+
+fn create_shipping_bin(mut cmd: Commands, assets: Res<AssetServer>) {
+    let scene = assets.load(GameAssetPath::new_model("::crate-color.glb").gltf_scene());
+    spawn_interactable_prop(
+        &mut cmd,
+        scene,
+        // TODO: replace the crate with an actual shipping bin model
+        Transform::from_xyz(4., 0., -8.).with_scale(Vec3::new(2., 1.5, 1.5)),
+        "Shipping Bin",
+    )
+    .insert(ShippingBin::default())
+    .observe(ship_held_item);
+}
+
+/// Puts the whole stack in the selected hotbar slot into the bin. Items that aren't worth
+/// anything, like tools, are refused
+fn ship_held_item(
+    trigger: Trigger<PlayerInteract>, mut bins: Query<&mut ShippingBin>,
+    mut player_query: Query<(&mut Inventory, &HotbarSelection), With<PlayerMarker>>,
+    items: ItemDefinitions,
+) {
+    let Ok(mut bin) = bins.get_mut(trigger.entity()) else {
+        return;
+    };
+    let Ok((mut inventory, selection)) = player_query.get_single_mut() else {
+        return;
+    };
+    let Some(stack) = inventory.slot(selection.0) else {
+        return;
+    };
+    let Some(data) = items.get(&stack.id) else {
+        error!("Can't ship unknown item \"{}\"", stack.id.0);
+        return;
+    };
+    if data.sell_price == 0 {
+        info!("{} can't be sold", data.display_name());
+        return;
+    }
+    let amount = stack.amount;
+    let Some(stack) = inventory.take(selection.0, amount) else {
+        return;
+    };
+    info!("Shipped {} {}", stack.amount, data.display_name());
+    match bin.0.iter_mut().find(|shipped| shipped.stacks_with(&stack)) {
+        Some(shipped) => shipped.amount += stack.amount,
+        None => bin.0.push(stack),
+    }
+}
+
+/// Empties every shipping bin into the wallet at the start of a new day
+fn sell_shipped_items(
+    _: Trigger<NewDay>, mut bins: Query<&mut ShippingBin>, mut wallet: ResMut<Wallet>,
+    items: ItemDefinitions, mut cmd: Commands,
+) {
+    let mut sold = Vec::new();
+    for mut bin in bins.iter_mut() {
+        for stack in bin.0.drain(..) {
+            let Some(data) = items.get(&stack.id) else {
+                error!("Shipped unknown item \"{}\", it's been lost", stack.id.0);
+                continue;
+            };
+            sold.push(SoldItem {
+                earned: data.sell_price_for(stack.quality) * stack.amount,
+                item: stack.id,
+                quality: stack.quality,
+                amount: stack.amount,
+            });
+        }
+    }
+    if sold.is_empty() {
+        return;
+    }
+    let total = sold.iter().map(|item| item.earned).sum();
+    wallet.earn(total);
+    cmd.trigger(DayEarnings { sold, total });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wallet_refuses_overspending() {
+        let mut wallet = Wallet::default();
+        wallet.earn(50);
+        assert!(!wallet.spend(60));
+        assert_eq!(wallet.0, 50);
+        assert!(wallet.spend(50));
+        assert_eq!(wallet.0, 0);
+    }
+}
@@ -0,0 +1,268 @@
+use bevy::prelude::*;
+use bevy_alt_ui_navigation_lite::prelude::{Focusable, Focused, NavEvent, NavEventReaderExt};
+use leafwing_input_manager::prelude::ActionState;
+
+use crate::{
+    common_events::{PlayerInteract, PlayerPassedOut, PlayerSlept},
+    data::game_asset_path::GameAssetPath,
+    interaction::spawn_interactable_prop,
+    mouse::{MouseState, MouseStateRequest},
+    player::{InputActions, PlayerMarker},
+};
+
+pub struct SleepPlugin;
+
+impl Plugin for SleepPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<SleepState>();
+        app.init_resource::<SleepInfo>();
+        app.add_systems(Startup, (create_bed, create_fade_overlay));
+        app.add_systems(OnEnter(SleepState::Prompt), open_sleep_prompt);
+        app.add_systems(OnExit(SleepState::Prompt), close_sleep_prompt);
+        app.add_systems(
+            Update,
+            (
+                (handle_sleep_prompt, highlight_prompt_buttons)
+                    .run_if(in_state(SleepState::Prompt)),
+                fade_out.run_if(in_state(SleepState::FadingOut)),
+                fade_in.run_if(in_state(SleepState::FadingIn)),
+            ),
+        );
+        app.observe(pass_out);
+    }
+}
+
+const FADE_SECONDS: f32 = 1.5;
+const BUTTON_COLOR: Color = Color::srgb(0.15, 0.15, 0.2);
+const FOCUSED_BUTTON_COLOR: Color = Color::srgb(0.3, 0.3, 0.4);
+
+#[derive(States, Debug, Hash, PartialEq, Eq, Clone, Default)]
+pub enum SleepState {
+    #[default]
+    Awake,
+    /// Asking the player whether they really want to go to bed
+    Prompt,
+    FadingOut,
+    FadingIn,
+}
+
+#[derive(Resource)]
+struct SleepInfo {
+    passed_out: bool,
+    fade: Timer,
+}
+
+impl Default for SleepInfo {
+    fn default() -> Self {
+        Self {
+            passed_out: false,
+            fade: Timer::from_seconds(FADE_SECONDS, TimerMode::Once),
+        }
+    }
+}
+
+#[derive(Component)]
+struct FadeOverlay;
+
+#[derive(Component)]
+struct SleepPrompt;
+
+#[derive(Component, Clone, Copy)]
+enum SleepPromptButton {
+    Sleep,
+    StayUp,
+}
+
+fn create_bed(mut cmd: Commands, assets: Res<AssetServer>) {
+    let scene = assets.load(GameAssetPath::new_model("::crate-color.glb").gltf_scene());
+    spawn_interactable_prop(
+        &mut cmd,
+        scene,
+        // TODO: replace the squashed crate with an actual bed model
+        Transform::from_xyz(8., 0., -6.).with_scale(Vec3::new(2., 1., 4.)),
+        "Bed",
+    )
+    .observe(request_sleep);
+}
+
+fn create_fade_overlay(mut cmd: Commands) {
+    cmd.spawn((
+        Name::new("Sleep Fade Overlay"),
+        FadeOverlay,
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                ..default()
+            },
+            background_color: Color::BLACK.with_alpha(0.).into(),
+            z_index: ZIndex::Global(100),
+            ..default()
+        },
+    ));
+}
+
+fn request_sleep(
+    _: Trigger<PlayerInteract>, state: Res<State<SleepState>>,
+    mut next: ResMut<NextState<SleepState>>,
+) {
+    if *state.get() == SleepState::Awake {
+        next.set(SleepState::Prompt);
+    }
+}
+
+fn pass_out(
+    _: Trigger<PlayerPassedOut>, state: Res<State<SleepState>>,
+    mut next: ResMut<NextState<SleepState>>, mut info: ResMut<SleepInfo>, mut cmd: Commands,
+) {
+    match state.get() {
+        SleepState::Awake => (),
+        SleepState::Prompt => cmd.trigger(MouseStateRequest(MouseState::Locked)),
+        // already on the way to bed
+        SleepState::FadingOut | SleepState::FadingIn => return,
+    }
+    warn!("Player passed out from staying up too late");
+    info.passed_out = true;
+    next.set(SleepState::FadingOut);
+}
+
+fn open_sleep_prompt(mut cmd: Commands) {
+    cmd.trigger(MouseStateRequest(MouseState::Free));
+    cmd.spawn((
+        Name::new("Sleep Prompt"),
+        SleepPrompt,
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(16.),
+                ..default()
+            },
+            ..default()
+        },
+    ))
+    .with_children(|b| {
+        b.spawn(TextBundle::from_section(
+            "Go to sleep for the night?",
+            TextStyle::default(),
+        ));
+        b.spawn(NodeBundle {
+            style: Style {
+                column_gap: Val::Px(16.),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|row| {
+            spawn_prompt_button(row, "Sleep", SleepPromptButton::Sleep);
+            spawn_prompt_button(row, "Stay up", SleepPromptButton::StayUp);
+        });
+    });
+}
+
+fn spawn_prompt_button(b: &mut ChildBuilder, label: &str, kind: SleepPromptButton) {
+    b.spawn((
+        kind,
+        Focusable::new(),
+        ButtonBundle {
+            style: Style {
+                padding: UiRect::axes(Val::Px(16.), Val::Px(8.)),
+                ..default()
+            },
+            background_color: BUTTON_COLOR.into(),
+            ..default()
+        },
+    ))
+    .with_children(|b| {
+        b.spawn(TextBundle::from_section(label, TextStyle::default()));
+    });
+}
+
+fn close_sleep_prompt(mut cmd: Commands, query: Query<Entity, With<SleepPrompt>>) {
+    for entity in query.iter() {
+        cmd.entity(entity).despawn_recursive();
+    }
+}
+
+/// Buttons are picked through the navigation plugin so the prompt works with a mouse, keyboard or
+/// gamepad alike. Cancelling is the same as staying up
+fn handle_sleep_prompt(
+    mut events: EventReader<NavEvent>, button_query: Query<&SleepPromptButton>,
+    input_query: Query<&ActionState<InputActions>, With<PlayerMarker>>,
+    mut next: ResMut<NextState<SleepState>>, mut info: ResMut<SleepInfo>, mut cmd: Commands,
+) {
+    let mut choice = events
+        .nav_iter()
+        .activated_in_query(&button_query)
+        .last()
+        .copied();
+    if input_query
+        .get_single()
+        .is_ok_and(|input| input.just_pressed(&InputActions::Cancel))
+    {
+        choice = Some(SleepPromptButton::StayUp);
+    }
+    let Some(choice) = choice else {
+        return;
+    };
+    cmd.trigger(MouseStateRequest(MouseState::Locked));
+    match choice {
+        SleepPromptButton::Sleep => {
+            info.passed_out = false;
+            next.set(SleepState::FadingOut);
+        }
+        SleepPromptButton::StayUp => next.set(SleepState::Awake),
+    }
+}
+
+fn highlight_prompt_buttons(
+    mut query: Query<(&mut BackgroundColor, Has<Focused>), With<SleepPromptButton>>,
+) {
+    for (mut color, focused) in query.iter_mut() {
+        color.0 = if focused {
+            FOCUSED_BUTTON_COLOR
+        } else {
+            BUTTON_COLOR
+        };
+    }
+}
+
+fn fade_out(
+    mut query: Query<&mut BackgroundColor, With<FadeOverlay>>, mut info: ResMut<SleepInfo>,
+    mut next: ResMut<NextState<SleepState>>, mut cmd: Commands, time: Res<Time>,
+) {
+    info.fade.tick(time.delta());
+    let alpha = info.fade.fraction();
+    for mut color in query.iter_mut() {
+        color.0 = Color::BLACK.with_alpha(alpha);
+    }
+    if info.fade.just_finished() {
+        // the day changes over while the screen is black
+        cmd.trigger(PlayerSlept {
+            passed_out: info.passed_out,
+        });
+        info.fade.reset();
+        next.set(SleepState::FadingIn);
+    }
+}
+
+fn fade_in(
+    mut query: Query<&mut BackgroundColor, With<FadeOverlay>>, mut info: ResMut<SleepInfo>,
+    mut next: ResMut<NextState<SleepState>>, time: Res<Time>,
+) {
+    info.fade.tick(time.delta());
+    let alpha = 1.0 - info.fade.fraction();
+    for mut color in query.iter_mut() {
+        color.0 = Color::BLACK.with_alpha(alpha);
+    }
+    if info.fade.just_finished() {
+        info.fade.reset();
+        info.passed_out = false;
+        next.set(SleepState::Awake);
+    }
+}
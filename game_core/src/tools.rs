@@ -0,0 +1,180 @@
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::ActionState;
+
+use crate::{
+    common_events::ToolUsed,
+    crops::{registry::CropDefinitions, systems::plant_crop},
+    days::Calendar,
+    energy::Energy,
+    farm::{FarmGrid, SoilTile},
+    items::{
+        inventory::{HotbarSelection, Inventory},
+        registry::ItemDefinitions,
+        ItemId,
+    },
+    mouse::MouseState,
+    player::{AimPoint, InputActions, LastInteractable, PlayerMarker},
+};
+
+pub struct ToolsPlugin;
+
+impl Plugin for ToolsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                give_starting_items,
+                (use_tools, plant_seeds).run_if(in_state(MouseState::Locked)),
+            ),
+        );
+    }
+}
+
+/// How far from the player a tool can reach
+pub const TOOL_REACH: f32 = 6.0;
+
+/// Items the player starts out with in their hotbar
+const STARTING_ITEMS: &[(&str, u32)] = &[
+    ("hoe", 1),
+    ("watering_can", 1),
+    ("scythe", 1),
+    ("axe", 1),
+    ("corn_seeds", 10),
+    ("beet_seeds", 10),
+];
+
+fn give_starting_items(
+    mut query: Query<&mut Inventory, With<PlayerMarker>>, items: ItemDefinitions,
+    mut given: Local<bool>,
+) {
+    if *given || !items.is_loaded() {
+        return;
+    }
+    let Ok(mut inventory) = query.get_single_mut() else {
+        return;
+    };
+    *given = true;
+    for (id, amount) in STARTING_ITEMS {
+        let Some(data) = items.get(&ItemId(id.to_string())) else {
+            error!("Missing starting item \"{}\"", id);
+            continue;
+        };
+        inventory.add(data, *amount);
+    }
+}
+
+/// Uses the tool in the selected hotbar slot on whatever the player is aiming at. Every swing
+/// costs energy, and exhausted players can't swing at all
+fn use_tools(
+    mut player_query: Query<
+        (
+            &ActionState<InputActions>,
+            &Inventory,
+            &HotbarSelection,
+            &LastInteractable,
+            &AimPoint,
+            &GlobalTransform,
+            &mut Energy,
+        ),
+        With<PlayerMarker>,
+    >,
+    transforms: Query<&GlobalTransform>, items: ItemDefinitions, mut cmd: Commands,
+) {
+    let Ok((input, inventory, selection, target, aim, player, mut energy)) =
+        player_query.get_single_mut()
+    else {
+        return;
+    };
+    let secondary = if input.just_pressed(&InputActions::Primary) {
+        false
+    } else if input.just_pressed(&InputActions::Secondary) {
+        true
+    } else {
+        return;
+    };
+    let Some(tool) = inventory
+        .slot(selection.0)
+        .and_then(|stack| items.get(&stack.id))
+        .and_then(|data| data.tool)
+    else {
+        return;
+    };
+
+    let in_reach = |point: Vec3| point.distance(player.translation()) <= TOOL_REACH;
+    let point = aim.0.filter(|point| in_reach(*point));
+    let target = target.0.filter(|target| {
+        transforms
+            .get(*target)
+            .is_ok_and(|trans| in_reach(trans.translation()))
+    });
+    let event = ToolUsed {
+        tool,
+        secondary,
+        point,
+    };
+    if target.is_none() && point.is_none() {
+        return;
+    }
+    if !energy.spend(tool.energy_cost()) {
+        info!("Too exhausted to use the {:?}", tool);
+        return;
+    }
+    match target {
+        Some(target) => cmd.trigger_targets(event, target),
+        None => cmd.trigger(event),
+    }
+}
+
+/// Plants the seed in the selected hotbar slot into the tilled tile being aimed at, using up one
+/// seed. Nothing is used up if the crop can't grow this season
+fn plant_seeds(
+    mut player_query: Query<
+        (
+            &ActionState<InputActions>,
+            &mut Inventory,
+            &HotbarSelection,
+            &AimPoint,
+            &GlobalTransform,
+        ),
+        With<PlayerMarker>,
+    >,
+    grid: Res<FarmGrid>, mut tiles: Query<&mut SoilTile>, crops: CropDefinitions,
+    items: ItemDefinitions, calendar: Res<Calendar>, mut cmd: Commands,
+) {
+    let Ok((input, mut inventory, selection, aim, player)) = player_query.get_single_mut() else {
+        return;
+    };
+    if !input.just_pressed(&InputActions::Primary) {
+        return;
+    }
+    let Some(crop) = inventory
+        .slot(selection.0)
+        .and_then(|stack| items.get(&stack.id))
+        .and_then(|data| data.crop.as_ref())
+    else {
+        return;
+    };
+    let Some(point) = aim
+        .0
+        .filter(|point| point.distance(player.translation()) <= TOOL_REACH)
+    else {
+        return;
+    };
+    let Some(tile_entity) = grid.tile_at(point) else {
+        return;
+    };
+    let Ok(mut tile) = tiles.get_mut(tile_entity) else {
+        return;
+    };
+    let Some((handle, def)) = crops.get(crop) else {
+        error!("Seed plants unknown crop \"{}\"", crop);
+        return;
+    };
+    if !def.grows_in(calendar.season) {
+        info!("{} can't be planted in {:?}", def.id, calendar.season);
+        return;
+    }
+    if plant_crop(&mut cmd, handle, tile_entity, &mut tile).is_some() {
+        inventory.take(selection.0, 1);
+    }
+}
@@ -4,17 +4,20 @@ use bevy::prelude::*;
 use bevy_hanabi::{EffectAsset, EffectSpawner, ParticleEffect, ParticleEffectBundle};
 use crop_vfx::CropVfx;
 use interaction_selection::InteractionSelectionPlugin;
+use weather_vfx::WeatherVfx;
 
 use crate::data::named_asset_id::NamedAssets;
 
 pub struct VfxPlugin;
 pub mod crop_vfx;
 pub mod interaction_selection;
+pub mod weather_vfx;
 
 impl Plugin for VfxPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(CropVfx);
         app.add_plugins(InteractionSelectionPlugin);
+        app.add_plugins(WeatherVfx);
         app.init_asset::<VfxAsset>();
         app.init_resource::<NamedAssets<VfxAsset>>();
         app.add_systems(Update, despawn_vfx);
@@ -0,0 +1,133 @@
+use bevy::prelude::*;
+use bevy_hanabi::{
+    Attribute, ColorOverLifetimeModifier, EffectAsset, EffectSpawner, Gradient, Module, OrientMode,
+    OrientModifier, ParticleEffectBundle, SetAttributeModifier, SetPositionCircleModifier,
+    ShapeDimension, SizeOverLifetimeModifier, Spawner,
+};
+
+use crate::weather::{Forecast, Weather};
+
+pub struct WeatherVfx;
+
+impl Plugin for WeatherVfx {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, init_vfx);
+        app.add_systems(Update, (toggle_weather_vfx, follow_camera));
+    }
+}
+
+/// How far above the camera precipitation spawns
+const SKY_HEIGHT: f32 = 15.0;
+/// Radius around the camera that precipitation covers
+const SKY_RADIUS: f32 = 25.0;
+
+/// A looping precipitation effect, which is only active during its weather
+#[derive(Component)]
+struct Precipitation(Vec<Weather>);
+
+fn init_vfx(mut cmd: Commands, mut effects: ResMut<Assets<EffectAsset>>) {
+    let rain = precipitation_effect(
+        "Rain VFX",
+        2000.0,
+        Vec3::new(0.5, -25., 0.),
+        Vec2::new(0.03, 0.6),
+        Color::srgba(0.6, 0.7, 0.9, 0.6),
+        OrientMode::AlongVelocity,
+    );
+    let snow = precipitation_effect(
+        "Snow VFX",
+        600.0,
+        Vec3::new(0.3, -2., 0.2),
+        Vec2::splat(0.12),
+        Color::srgba(1.0, 1.0, 1.0, 0.9),
+        OrientMode::FaceCameraPosition,
+    );
+    cmd.spawn((
+        Name::new("Rain VFX instance"),
+        Precipitation(vec![Weather::Rain, Weather::Storm]),
+        ParticleEffectBundle::new(effects.add(rain)),
+    ));
+    cmd.spawn((
+        Name::new("Snow VFX instance"),
+        Precipitation(vec![Weather::Snow]),
+        ParticleEffectBundle::new(effects.add(snow)),
+    ));
+}
+
+fn precipitation_effect(
+    name: &str, rate: f32, velocity: Vec3, size: Vec2, color: Color, orient: OrientMode,
+) -> EffectAsset {
+    let mut module = Module::default();
+    let init_pos = SetPositionCircleModifier {
+        center: module.lit(Vec3::ZERO),
+        axis: module.lit(Vec3::Y),
+        radius: module.lit(SKY_RADIUS),
+        dimension: ShapeDimension::Volume,
+    };
+    let init_vel = SetAttributeModifier::new(Attribute::VELOCITY, module.lit(velocity));
+    // long enough to reach the ground from the sky
+    let lifetime = SetAttributeModifier::new(
+        Attribute::LIFETIME,
+        module.lit(SKY_HEIGHT * 1.5 / velocity.length()),
+    );
+
+    let mut gradient = Gradient::new();
+    gradient.add_key(0.0, color.to_linear().to_vec4());
+    gradient.add_key(1.0, color.to_linear().to_vec4());
+
+    let mut sizes = Gradient::new();
+    sizes.add_key(0.0, size);
+    sizes.add_key(1.0, size);
+
+    EffectAsset::new(vec![8192], Spawner::rate(rate.into()), module)
+        .with_name(name)
+        .init(init_pos)
+        .init(init_vel)
+        .init(lifetime)
+        .render(ColorOverLifetimeModifier { gradient })
+        .render(OrientModifier {
+            mode: orient,
+            rotation: None,
+        })
+        .render(SizeOverLifetimeModifier {
+            gradient: sizes,
+            screen_space_size: false,
+        })
+}
+
+fn toggle_weather_vfx(
+    forecast: Res<Forecast>,
+    mut query: Query<(&Precipitation, &mut Visibility, Option<&mut EffectSpawner>)>,
+) {
+    for (precipitation, mut visibility, spawner) in query.iter_mut() {
+        let active = precipitation.0.contains(&forecast.today);
+        let target = if active {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        if *visibility != target {
+            *visibility = target;
+        }
+        // the spawner only shows up once the effect has been compiled
+        if let Some(mut spawner) = spawner {
+            if spawner.is_active() != active {
+                spawner.set_active(active);
+            }
+        }
+    }
+}
+
+/// Keeps the precipitation centered over the camera, particles are simulated in world space so
+/// they don't drag along with it
+fn follow_camera(
+    camera_query: Query<&GlobalTransform, With<Camera3d>>,
+    mut query: Query<&mut Transform, With<Precipitation>>,
+) {
+    let Some(camera) = camera_query.iter().next() else {
+        return;
+    };
+    for mut trans in query.iter_mut() {
+        trans.translation = camera.translation() + Vec3::Y * SKY_HEIGHT;
+    }
+}
@@ -0,0 +1,158 @@
+use std::fmt;
+
+use bevy::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common_events::NewDay,
+    crops::components::{CropData, CropFruit, CropStatus},
+    days::{Calendar, Season},
+    farm::{SoilState, SoilTile},
+    Random,
+};
+
+pub struct WeatherPlugin;
+
+impl Plugin for WeatherPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Forecast>();
+        app.add_systems(Startup, roll_first_forecast);
+        app.add_systems(Update, rain_waters_soil);
+        app.observe(roll_weather);
+    }
+}
+
+/// Chance for each crop to be destroyed when a storm rolls in, out of 100
+const STORM_DAMAGE_CHANCE: u32 = 15;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+pub enum Weather {
+    #[default]
+    Sunny,
+    Rain,
+    Storm,
+    Snow,
+}
+
+impl Weather {
+    /// Relative odds of each kind of weather for a season
+    pub fn odds(season: Season) -> &'static [(Weather, u32)] {
+        match season {
+            Season::Spring => &[(Weather::Sunny, 6), (Weather::Rain, 3), (Weather::Storm, 1)],
+            Season::Summer => &[(Weather::Sunny, 7), (Weather::Rain, 2), (Weather::Storm, 1)],
+            Season::Autumn => &[(Weather::Sunny, 5), (Weather::Rain, 4), (Weather::Storm, 1)],
+            Season::Winter => &[(Weather::Sunny, 5), (Weather::Snow, 5)],
+        }
+    }
+
+    /// Picks the weather for a season, where `roll` is somewhere below the total odds
+    pub fn pick(season: Season, mut roll: u32) -> Weather {
+        for (weather, weight) in Self::odds(season) {
+            if roll < *weight {
+                return *weather;
+            }
+            roll -= weight;
+        }
+        Weather::Sunny
+    }
+
+    pub fn roll(season: Season, rng: &mut ResMut<Random>) -> Weather {
+        let total: u32 = Self::odds(season).iter().map(|(_, weight)| weight).sum();
+        Self::pick(season, rng.gen_range(0..total))
+    }
+
+    /// Whether soil gets watered for free today
+    pub fn waters_soil(self) -> bool {
+        matches!(self, Weather::Rain | Weather::Storm)
+    }
+
+    /// How much of the usual sunlight makes it through the clouds
+    pub fn light_factor(self) -> f32 {
+        match self {
+            Weather::Sunny => 1.0,
+            Weather::Rain => 0.5,
+            Weather::Storm => 0.3,
+            Weather::Snow => 0.7,
+        }
+    }
+}
+
+impl fmt::Display for Weather {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Today's weather, plus what tomorrow is going to bring
+#[derive(Resource, Debug, Default, Clone)]
+pub struct Forecast {
+    pub today: Weather,
+    pub tomorrow: Weather,
+}
+
+/// The first day is always clear, but what comes after it is rolled like any other day
+fn roll_first_forecast(
+    mut forecast: ResMut<Forecast>, calendar: Res<Calendar>, mut rng: ResMut<Random>,
+) {
+    let tomorrow = calendar.clone().advance();
+    forecast.today = Weather::Sunny;
+    forecast.tomorrow = Weather::roll(tomorrow.season, &mut rng);
+}
+
+/// Moves the forecast along a day and rolls a fresh one for tomorrow's season
+fn roll_weather(
+    _: Trigger<NewDay>, mut forecast: ResMut<Forecast>, calendar: Res<Calendar>,
+    mut rng: ResMut<Random>, crops: Query<(&CropStatus, Option<&Name>, Entity), With<CropData>>,
+    mut cmd: Commands,
+) {
+    let tomorrow = calendar.clone().advance();
+    forecast.today = forecast.tomorrow;
+    forecast.tomorrow = Weather::roll(tomorrow.season, &mut rng);
+    info!(
+        "Weather today: {}, tomorrow: {}",
+        forecast.today, forecast.tomorrow
+    );
+
+    if forecast.today != Weather::Storm {
+        return;
+    }
+    for (status, name, entity) in crops.iter() {
+        if *status == CropStatus::Dead || rng.gen_range(0..100) >= STORM_DAMAGE_CHANCE {
+            continue;
+        }
+        info!("Crop destroyed by the storm: {:} ({:?})", entity, name);
+        // like withering, the crop is cleared away on the next day
+        cmd.entity(entity)
+            .insert(CropStatus::Dead)
+            .remove::<CropFruit>();
+    }
+}
+
+/// Keeps every tilled tile watered while it's raining, including ones tilled during the day
+fn rain_waters_soil(forecast: Res<Forecast>, mut tiles: Query<&mut SoilTile>) {
+    if !forecast.today.waters_soil() {
+        return;
+    }
+    for mut tile in tiles.iter_mut() {
+        if tile.state == SoilState::Tilled {
+            tile.water();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_follow_seasonal_odds() {
+        assert_eq!(Weather::pick(Season::Spring, 0), Weather::Sunny);
+        assert_eq!(Weather::pick(Season::Spring, 6), Weather::Rain);
+        assert_eq!(Weather::pick(Season::Spring, 9), Weather::Storm);
+        for roll in 0..10 {
+            assert_ne!(Weather::pick(Season::Summer, roll), Weather::Snow);
+            assert!(!Weather::pick(Season::Winter, roll).waters_soil());
+        }
+    }
+}